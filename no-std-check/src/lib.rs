@@ -0,0 +1,116 @@
+#![no_std]
+
+use indicator::{operators::Together, *};
+
+pub struct Bar {
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+impl High for Bar {
+    fn high(&self) -> f64 {
+        self.high
+    }
+}
+impl Low for Bar {
+    fn low(&self) -> f64 {
+        self.low
+    }
+}
+impl Close for Bar {
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
+impl Price for Bar {
+    fn price(&self) -> f64 {
+        self.close
+    }
+}
+impl Volume for Bar {
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+}
+
+/// Indicators for one symbol, none of which allocates
+pub struct Feed {
+    pub sma: SmaN<20>,
+    pub wma: Wma<f32, Inline<20>>,
+    pub vwma: VwmaN<20>,
+    pub momentum: Momentum<f64, Inline<11>>,
+    pub max: MaxN<20>,
+    pub min: MinN<20>,
+    pub sd: StandardDeviationN<20>,
+    pub ema: Ema,
+    pub rsi: Rsi,
+    pub atr: Atr,
+    pub vwap: Vwap,
+    pub obv: Obv,
+    pub parabolic_sar: ParabolicSar,
+    pub trix: Trix,
+    pub channel: Together<Max<f64, Inline<20>>, Min<f64, Inline<20>>>,
+}
+impl Feed {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            sma: SmaN::default(),
+            wma: Wma::with_period(20)?,
+            vwma: VwmaN::default(),
+            momentum: Momentum::with_period(10)?,
+            max: MaxN::default(),
+            min: MinN::default(),
+            sd: StandardDeviationN::default(),
+            ema: Ema::new(20)?,
+            rsi: Rsi::new(14)?,
+            atr: Atr::new(14)?,
+            vwap: Vwap::new(),
+            obv: Obv::new(),
+            parabolic_sar: ParabolicSar::default(),
+            trix: Trix::new(15)?,
+            channel: Max::with_period(20)?.together(Min::with_period(20)?),
+        })
+    }
+
+    pub fn next(&mut self, bar: &Bar) -> f64 {
+        let (upper, lower) = self.channel.next(bar);
+        upper - lower
+            + self.sma.next(bar)
+            + self.wma.next(bar.close as f32) as f64
+            + self.vwma.next(bar)
+            + self.momentum.next(bar)
+            + self.max.next(bar)
+            + self.min.next(bar)
+            + self.sd.next(bar).sd
+            + self.ema.next(bar)
+            + self.rsi.next(bar)
+            + self.atr.next(bar)
+            + self.vwap.next(bar)
+            + self.obv.next(bar)
+            + self.parabolic_sar.next(bar).sar
+            + self.trix.next(bar)
+    }
+}
+
+/// Indicators that keep windows on the heap
+#[cfg(feature = "alloc")]
+pub mod heap {
+    use indicator::*;
+
+    pub fn macd_histogram(inputs: &[f64]) -> Option<f64> {
+        let mut macd = Macd::default()
+            .map(|MacdOutput { histogram, .. }| histogram)
+            .auto_mature();
+        inputs.iter().fold(None, |_, &x| macd.next(x))
+    }
+
+    pub fn last_window_sum(inputs: &[f64]) -> f64 {
+        let mut window = Sma::new(5).unwrap().window(3);
+        let mut sum = 0.0;
+        for &x in inputs {
+            sum = window.next_window(x).iter().sum();
+        }
+        sum
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(feature = "alloc")]
+mod moment_window;
+mod monotonic_deque;
+mod neumaier_sum;
+#[cfg(feature = "alloc")]
+mod order_statistic_tree;
+
+#[cfg(feature = "alloc")]
+pub(crate) use moment_window::*;
+pub(crate) use monotonic_deque::*;
+pub(crate) use neumaier_sum::*;
+#[cfg(feature = "alloc")]
+pub(crate) use order_statistic_tree::*;
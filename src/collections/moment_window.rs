@@ -0,0 +1,178 @@
+use alloc::collections::VecDeque;
+
+/// Ring buffer with streaming power sums up to the fourth order
+///
+/// The sums are taken over the deviations from a shift, which is reset to the mean of
+/// the window every `period` updates while the sums are rebuilt from the ring.
+/// This keeps the deviations small, so the power sums do not lose precision
+/// to cancellation, and bounds the accumulated rounding error at O(1) amortized cost.
+/// Once `period` equal values in a row have come in, the sums are zeroed around them,
+/// so that a flat window has exactly zero central moments rather than rounding residue.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct MomentWindow {
+    period: usize,
+    ring: VecDeque<f64>,
+    shift: f64,
+    sums: [f64; 4],
+    since_rebuild: usize,
+    repeats: usize,
+}
+
+/// Mean and central moments of a window
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Moments {
+    pub mean: f64,
+    pub m2: f64,
+    pub m3: f64,
+    pub m4: f64,
+}
+
+impl MomentWindow {
+    pub fn new(period: usize) -> Self {
+        Self {
+            period,
+            ring: VecDeque::with_capacity(period),
+            shift: 0.0,
+            sums: [0.0; 4],
+            since_rebuild: 0,
+            repeats: 0,
+        }
+    }
+
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.ring.clear();
+        self.shift = 0.0;
+        self.sums = [0.0; 4];
+        self.since_rebuild = 0;
+        self.repeats = 0;
+    }
+
+    /// Push `input` out of the oldest value, or fill the window with it if empty.
+    pub fn push(&mut self, input: f64) {
+        if self.ring.is_empty() {
+            for _ in 0..self.period {
+                self.ring.push_back(input);
+            }
+            self.shift = input;
+            self.sums = [0.0; 4];
+            self.repeats = self.period;
+            return;
+        }
+
+        let prev_input = *self.ring.back().unwrap();
+        let old_input = self.ring.pop_front().unwrap();
+        self.ring.push_back(input);
+
+        self.repeats = if input == prev_input {
+            self.repeats + 1
+        } else {
+            1
+        };
+        self.since_rebuild += 1;
+        if self.period <= self.repeats {
+            self.since_rebuild = 0;
+            self.shift = input;
+            self.sums = [0.0; 4];
+        } else if self.since_rebuild < self.period {
+            let d_new = input - self.shift;
+            let d_old = old_input - self.shift;
+            let (mut p_new, mut p_old) = (1.0, 1.0);
+            for sum in self.sums.iter_mut() {
+                p_new *= d_new;
+                p_old *= d_old;
+                *sum += p_new - p_old;
+            }
+        } else {
+            self.rebuild();
+        }
+    }
+
+    fn rebuild(&mut self) {
+        self.since_rebuild = 0;
+        self.shift = self.ring.iter().sum::<f64>() / self.period as f64;
+        self.sums = [0.0; 4];
+        for x in self.ring.iter() {
+            let d = x - self.shift;
+            let mut p = 1.0;
+            for sum in self.sums.iter_mut() {
+                p *= d;
+                *sum += p;
+            }
+        }
+    }
+
+    pub fn moments(&self) -> Moments {
+        let n = self.period as f64;
+        let [s1, s2, s3, s4] = self.sums.map(|sum| sum / n);
+        let mu = s1;
+        let mu2 = mu * mu;
+        Moments {
+            mean: self.shift + mu,
+            m2: (s2 - mu2).max(0.0),
+            m3: s3 - 3.0 * mu * s2 + 2.0 * mu2 * mu,
+            m4: (s4 - 4.0 * mu * s3 + 6.0 * mu2 * s2 - 3.0 * mu2 * mu2).max(0.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helper::*, Price};
+
+    #[test]
+    fn compare_with_naive() {
+        const PERIOD: usize = 20;
+        let mut window = MomentWindow::new(PERIOD);
+        let mut ring = VecDeque::new();
+
+        // A steady trend moves the data away from any fixed shift
+        for (i, x) in RANDOM_DATA.iter().enumerate() {
+            let x = x.price() + i as f64;
+            window.push(x);
+            if ring.is_empty() {
+                ring.extend([x; PERIOD]);
+            } else {
+                ring.pop_front();
+                ring.push_back(x);
+            }
+
+            let n = PERIOD as f64;
+            let mean = ring.iter().sum::<f64>() / n;
+            let central = |p| ring.iter().map(|x| (x - mean).powi(p)).sum::<f64>() / n;
+
+            let moments = window.moments();
+            assert!((moments.mean - mean).abs() < PRECISION);
+            assert!((moments.m2 - central(2)).abs() < PRECISION);
+            assert!((moments.m3 - central(3)).abs() < PRECISION * 1e2);
+            assert!((moments.m4 - central(4)).abs() < PRECISION * 1e3);
+        }
+    }
+
+    #[test]
+    fn flat_window() {
+        const PERIOD: usize = 20;
+        let mut window = MomentWindow::new(PERIOD);
+
+        for x in RANDOM_DATA.iter() {
+            window.push(x.price() * 10.0);
+        }
+        for _ in 0..PERIOD {
+            window.push(1000.0);
+        }
+
+        let moments = window.moments();
+        assert_eq!(moments.mean, 1000.0);
+        assert_eq!((moments.m2, moments.m3, moments.m4), (0.0, 0.0, 0.0));
+    }
+}
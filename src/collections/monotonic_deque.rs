@@ -0,0 +1,81 @@
+use crate::{DefaultStorage, Number, Ring, Storage};
+
+/// Which extremum a [`MonotonicDeque`] tracks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Extremum {
+    Max,
+    Min,
+}
+
+/// Sliding window extremum in O(1) amortized per update
+///
+/// Keeps only the values that can still become the extremum of the window,
+/// in order of arrival. A value evicts every older value it ties or beats,
+/// so the front is always the newest occurrence of the extremum.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, S::Ring<(usize, T)>: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, S::Ring<(usize, T)>: serde::Deserialize<'de>"
+    ))
+)]
+pub(crate) struct MonotonicDeque<T: Number = f64, S: Storage = DefaultStorage> {
+    period: usize,
+    extremum: Extremum,
+    deque: S::Ring<(usize, T)>,
+    time: usize,
+}
+
+impl<T: Number, S: Storage> MonotonicDeque<T, S> {
+    pub fn new(period: usize, extremum: Extremum) -> Self {
+        Self {
+            period,
+            extremum,
+            deque: Ring::with_capacity(period),
+            time: 0,
+        }
+    }
+
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.time = self.time.wrapping_add(1);
+
+        // Expire first, so that the deque never holds more than `period` values
+        while let Some(&(time, _)) = self.deque.front() {
+            if self.time.wrapping_sub(time) < self.period {
+                break;
+            }
+            let _ = self.deque.pop_front();
+        }
+
+        while let Some(&(_, back)) = self.deque.back() {
+            let is_evicted = match self.extremum {
+                Extremum::Max => back <= value,
+                Extremum::Min => value <= back,
+            };
+            if !is_evicted {
+                break;
+            }
+            let _ = self.deque.pop_back();
+        }
+        self.deque.push_back((self.time, value));
+    }
+
+    /// Number of updates since the newest occurrence of the extremum, and the extremum
+    pub fn front(&self) -> Option<(usize, T)> {
+        self.deque
+            .front()
+            .map(|&(time, value)| (self.time.wrapping_sub(time), value))
+    }
+
+    pub fn clear(&mut self) {
+        self.deque.clear();
+        self.time = 0;
+    }
+}
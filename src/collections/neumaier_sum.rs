@@ -0,0 +1,68 @@
+use crate::Number;
+
+/// Running sum with Neumaier's compensation
+///
+/// Carries the low-order bits lost by each addition in a separate term,
+/// so adding and later subtracting the same values does not leave rounding residue behind.
+/// With an exact number type the compensation stays zero.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct NeumaierSum<T = f64> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T: Number> NeumaierSum<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            sum: value,
+            compensation: T::ZERO,
+        }
+    }
+
+    pub fn add(&mut self, value: T) {
+        let sum = self.sum + value;
+        self.compensation += if self.sum.abs() >= value.abs() {
+            (self.sum - sum) + value
+        } else {
+            (value - sum) + self.sum
+        };
+        self.sum = sum;
+    }
+
+    pub fn sub(&mut self, value: T) {
+        self.add(-value);
+    }
+
+    pub fn value(&self) -> T {
+        self.sum + self.compensation
+    }
+}
+impl<T: Number> Default for NeumaierSum<T> {
+    fn default() -> Self {
+        Self::new(T::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancellation() {
+        let mut sum = NeumaierSum::new(1.0);
+        sum.add(1e100);
+        sum.add(1.0);
+        sum.sub(1e100);
+        assert_eq!(sum.value(), 2.0);
+
+        let mut sum = NeumaierSum::<f64>::default();
+        for _ in 0..10 {
+            sum.add(0.1);
+        }
+        for _ in 0..10 {
+            sum.sub(0.1);
+        }
+        assert_eq!(sum.value(), 0.0);
+    }
+}
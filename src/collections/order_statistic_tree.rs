@@ -0,0 +1,311 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+const NIL: usize = usize::MAX;
+const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// Multiset of `f64` ordered by value, with rank and sum queries in expected O(log n)
+///
+/// Implemented as a treap whose nodes live in an arena and are augmented with the size and
+/// the sum of their subtrees. Subtree sums are rebuilt from the children on every update,
+/// so they never accumulate the drift of a running sum.
+///
+/// Values are ordered and matched by [`f64::total_cmp`], so a NaN is removed like any other value
+/// once it leaves the window. It sorts above every number, or below them if its sign bit is set.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct OrderStatisticTree {
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+    root: usize,
+    seed: u64,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Node {
+    value: f64,
+    priority: u64,
+    left: usize,
+    right: usize,
+    len: usize,
+    sum: f64,
+}
+
+impl OrderStatisticTree {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            root: NIL,
+            seed: SEED,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len_of(self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root == NIL
+    }
+
+    /// Sum of all values
+    pub fn sum(&self) -> f64 {
+        self.sum_of(self.root)
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.free.clear();
+        self.root = NIL;
+        self.seed = SEED;
+    }
+
+    pub fn insert(&mut self, value: f64) {
+        let node = self.alloc(value);
+        let (left, right) = self.split(self.root, value, false);
+        let left = self.merge(left, node);
+        self.root = self.merge(left, right);
+    }
+
+    /// Remove one occurrence of `value`, returning whether it was found.
+    pub fn remove(&mut self, value: f64) -> bool {
+        let (left, rest) = self.split(self.root, value, false);
+        let (equal, right) = self.split(rest, value, true);
+        let found = equal != NIL;
+        let equal = if found {
+            let Node {
+                left: l, right: r, ..
+            } = self.nodes[equal];
+            self.free.push(equal);
+            self.merge(l, r)
+        } else {
+            NIL
+        };
+        let left = self.merge(left, equal);
+        self.root = self.merge(left, right);
+        found
+    }
+
+    /// The `k`-th smallest value (0-based)
+    pub fn get(&self, mut k: usize) -> Option<f64> {
+        let mut t = self.root;
+        while t != NIL {
+            let node = &self.nodes[t];
+            let left_len = self.len_of(node.left);
+            match k {
+                k if k < left_len => t = node.left,
+                k if k == left_len => return node.value.into(),
+                _ => {
+                    k -= left_len + 1;
+                    t = node.right;
+                }
+            }
+        }
+        None
+    }
+
+    /// Number and sum of the values less than `value`
+    pub fn less_than(&self, value: f64) -> (usize, f64) {
+        self.fold_below(value, false)
+    }
+
+    /// Number and sum of the values less than or equal to `value`
+    pub fn less_equal(&self, value: f64) -> (usize, f64) {
+        self.fold_below(value, true)
+    }
+
+    fn fold_below(&self, value: f64, inclusive: bool) -> (usize, f64) {
+        let (mut len, mut sum) = (0, 0.0);
+        let mut t = self.root;
+        while t != NIL {
+            let node = &self.nodes[t];
+            if precedes(node.value, value, inclusive) {
+                len += self.len_of(node.left) + 1;
+                sum += self.sum_of(node.left) + node.value;
+                t = node.right;
+            } else {
+                t = node.left;
+            }
+        }
+        (len, sum)
+    }
+
+    fn alloc(&mut self, value: f64) -> usize {
+        // xorshift64
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        let node = Node {
+            value,
+            priority: self.seed,
+            left: NIL,
+            right: NIL,
+            len: 1,
+            sum: value,
+        };
+        match self.free.pop() {
+            Some(i) => {
+                self.nodes[i] = node;
+                i
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn len_of(&self, t: usize) -> usize {
+        if t == NIL {
+            0
+        } else {
+            self.nodes[t].len
+        }
+    }
+
+    fn sum_of(&self, t: usize) -> f64 {
+        if t == NIL {
+            0.0
+        } else {
+            self.nodes[t].sum
+        }
+    }
+
+    fn update(&mut self, t: usize) {
+        let Node {
+            value, left, right, ..
+        } = self.nodes[t];
+        self.nodes[t].len = self.len_of(left) + 1 + self.len_of(right);
+        self.nodes[t].sum = self.sum_of(left) + value + self.sum_of(right);
+    }
+
+    /// Split `t` into the values below `value` (or equal to it if `inclusive`) and the rest.
+    fn split(&mut self, t: usize, value: f64, inclusive: bool) -> (usize, usize) {
+        if t == NIL {
+            return (NIL, NIL);
+        }
+        let node_value = self.nodes[t].value;
+        if precedes(node_value, value, inclusive) {
+            let (left, right) = self.split(self.nodes[t].right, value, inclusive);
+            self.nodes[t].right = left;
+            self.update(t);
+            (t, right)
+        } else {
+            let (left, right) = self.split(self.nodes[t].left, value, inclusive);
+            self.nodes[t].left = right;
+            self.update(t);
+            (left, t)
+        }
+    }
+
+    /// Merge two trees where every value of `a` is less than or equal to every value of `b`.
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL {
+            return b;
+        }
+        if b == NIL {
+            return a;
+        }
+        if self.nodes[b].priority < self.nodes[a].priority {
+            let right = self.merge(self.nodes[a].right, b);
+            self.nodes[a].right = right;
+            self.update(a);
+            a
+        } else {
+            let left = self.merge(a, self.nodes[b].left);
+            self.nodes[b].left = left;
+            self.update(b);
+            b
+        }
+    }
+}
+
+/// Whether `a` goes below `value`, or is equal to it if `inclusive`, in the total order of `f64`
+fn precedes(a: f64, value: f64, inclusive: bool) -> bool {
+    match a.total_cmp(&value) {
+        Ordering::Less => true,
+        Ordering::Equal => inclusive,
+        Ordering::Greater => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helper::*, Price};
+
+    #[test]
+    fn compare_with_sorted_vec() {
+        let mut tree = OrderStatisticTree::with_capacity(16);
+        let mut sorted: Vec<f64> = Vec::new();
+
+        for (i, x) in RANDOM_DATA.iter().take(2000).enumerate() {
+            // Rounded to produce duplicates
+            let value = (x.price() * 4.0).round() / 4.0;
+            tree.insert(value);
+            let index = sorted.partition_point(|&y| y < value);
+            sorted.insert(index, value);
+
+            if i % 3 == 0 {
+                let old = sorted.remove(i % sorted.len());
+                assert!(tree.remove(old));
+            }
+            assert!(!tree.remove(-1.0));
+
+            assert_eq!(tree.len(), sorted.len());
+            assert!((tree.sum() - sorted.iter().sum::<f64>()).abs() < PRECISION * 1e4);
+            for (k, y) in sorted.iter().enumerate() {
+                assert_eq!(tree.get(k), Some(*y));
+            }
+            assert_eq!(tree.get(sorted.len()), None);
+
+            let lt = sorted.partition_point(|&y| y < value);
+            let le = sorted.partition_point(|&y| y <= value);
+            assert_eq!(tree.less_than(value).0, lt);
+            assert_eq!(tree.less_equal(value).0, le);
+            let sum_lt: f64 = sorted[..lt].iter().sum();
+            assert!((tree.less_than(value).1 - sum_lt).abs() < PRECISION * 1e4);
+        }
+
+        tree.clear();
+        assert!(tree.is_empty());
+        assert_eq!(tree.get(0), None);
+    }
+
+    #[test]
+    fn nan_ages_out() {
+        const PERIOD: usize = 5;
+        let mut tree = OrderStatisticTree::with_capacity(PERIOD);
+        let mut ring = alloc::collections::VecDeque::new();
+
+        let inputs = [
+            1.0,
+            2.0,
+            3.0,
+            4.0,
+            5.0,
+            f64::NAN,
+            6.0,
+            7.0,
+            8.0,
+            9.0,
+            10.0,
+            11.0,
+        ];
+        for x in inputs {
+            if ring.len() == PERIOD {
+                assert!(tree.remove(ring.pop_front().unwrap()));
+            }
+            ring.push_back(x);
+            tree.insert(x);
+            assert_eq!(tree.len(), ring.len());
+        }
+
+        assert_eq!(tree.len(), PERIOD);
+        assert_eq!(tree.sum(), 45.0);
+        assert_eq!(tree.get(0), Some(7.0));
+        assert_eq!(tree.get(PERIOD - 1), Some(11.0));
+    }
+}
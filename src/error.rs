@@ -2,9 +2,21 @@ use core::fmt::{Debug, Display, Formatter};
 
 #[derive(Debug, Clone)]
 pub enum Range<T> {
-    LowerBounded { min: T },
-    UpperBounded { max: T },
-    BothBounded { min: T, max: T },
+    LowerBounded {
+        min: T,
+    },
+    UpperBounded {
+        max: T,
+    },
+    BothBounded {
+        min: T,
+        max: T,
+    },
+    /// Bounded on both sides, excluding `min`
+    LeftOpen {
+        min: T,
+        max: T,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -49,6 +61,9 @@ impl<T: Display> Display for InvalidRangeError<T> {
             BothBounded { min, max } => f.write_fmt(format_args!(
                 "expected to be {min} <= {name} <= {max}, but actually {value}."
             )),
+            LeftOpen { min, max } => f.write_fmt(format_args!(
+                "expected to be {min} < {name} <= {max}, but actually {value}."
+            )),
         }
     }
 }
@@ -87,6 +102,7 @@ pub enum Error {
     InvalidUintRange(InvalidRangeError<usize>),
     InvalidFloatRange(InvalidRangeError<f64>),
     InvalidRelation(InvalidBinaryRelationError<usize>),
+    InvalidFloatRelation(InvalidBinaryRelationError<f64>),
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
@@ -95,6 +111,7 @@ impl Display for Error {
             InvalidUintRange(e) => f.write_fmt(format_args!("invalid uint range: {e}")),
             InvalidFloatRange(e) => f.write_fmt(format_args!("invalid float range: {e}")),
             InvalidRelation(e) => f.write_fmt(format_args!("invalid relation: {e}")),
+            InvalidFloatRelation(e) => f.write_fmt(format_args!("invalid float relation: {e}")),
         }
     }
 }
@@ -113,6 +130,11 @@ impl From<InvalidBinaryRelationError<usize>> for Error {
         Self::InvalidRelation(e)
     }
 }
+impl From<InvalidBinaryRelationError<f64>> for Error {
+    fn from(e: InvalidBinaryRelationError<f64>) -> Self {
+        Self::InvalidFloatRelation(e)
+    }
+}
 
 #[cfg(feature = "std")]
 impl std::error::Error for Error {}
@@ -0,0 +1,38 @@
+/// `f64` methods that only `std` provides, computed by `libm` without it
+///
+/// Whenever `std` is linked in anyway, as it is in tests, its inherent methods take precedence
+/// and the imports of this trait go unused, hence the `#[allow(unused_imports)]` on them.
+pub(crate) trait Float {
+    fn sqrt(self) -> Self;
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    fn atan(self) -> Self;
+    fn floor(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+}
+
+impl Float for f64 {
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    fn ln(self) -> Self {
+        libm::log(self)
+    }
+
+    fn exp(self) -> Self {
+        libm::exp(self)
+    }
+
+    fn atan(self) -> Self {
+        libm::atan(self)
+    }
+
+    fn floor(self) -> Self {
+        libm::floor(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        libm::pow(self, n.into())
+    }
+}
@@ -1,13 +1,18 @@
+#[cfg(feature = "alloc")]
+use crate::operators::{Rolling, Window};
 use crate::{
     indicator_iterator::IndicatorIterator,
-    operators::{Composition, Map, Mature, Together, Window},
-    Indicator, Next,
+    operators::{Composition, Map, Mature, Together},
+    Indicator, Lookback, Next,
 };
 
 /// Provides extended methods for Indicator.
 pub trait IndicatorExt: Indicator + Sized {
     /// Mature
     ///
+    /// Outputs `None` for the first `period` inputs.
+    /// Pass [`WarmupPeriod::warmup_period`](crate::WarmupPeriod::warmup_period) to drop exactly the warm-up.
+    ///
     /// # Example
     ///
     /// ```
@@ -28,6 +33,33 @@ pub trait IndicatorExt: Indicator + Sized {
         Mature::new(self, period)
     }
 
+    /// Mature by its own lookback
+    ///
+    /// Outputs `None` until [`Lookback::is_ready`] holds, which works through compositions
+    /// without counting the periods by hand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use indicator::*;
+    /// # fn main () {
+    /// let sma = Sma::new(2).unwrap().pullback(Sma::new(3).unwrap());
+    /// let mut sma = sma.auto_mature();
+    ///
+    /// assert_eq!(sma.next(1.0), None);
+    /// assert_eq!(sma.next(3.0), None);
+    /// assert_eq!(sma.next(5.0), None);
+    /// assert_eq!(sma.next(7.0), Some(4.0));
+    /// # }
+    /// ```
+    fn auto_mature(self) -> Mature<Self>
+    where
+        Self: Lookback,
+    {
+        let period = self.lookback();
+        Mature::new(self, period)
+    }
+
     /// Create a new indicator that applies a functional transformation to the output of the indicator.
     ///
     /// # Example
@@ -208,6 +240,9 @@ pub trait IndicatorExt: Indicator + Sized {
 
     /// Create a new indicator that outputs the past N output values ​​of the inner indicator.
     ///
+    /// Until N values have come in, the window is padded with the oldest one.
+    /// [`Window::next_window`] borrows the window instead of copying it into a `Vec`.
+    ///
     /// # Example
     ///
     /// ```
@@ -217,21 +252,60 @@ pub trait IndicatorExt: Indicator + Sized {
     ///
     /// let mut sma_window = sma.window(3);
     ///
-    /// assert_eq!(sma_window.next(100.0), &[&100.0, &100.0, &100.0]);
-    /// assert_eq!(sma_window.next(101.0), &[&100.0, &100.0, &100.2]);
-    /// assert_eq!(sma_window.next(101.0), &[&100.0, &100.2, &100.4]);
-    /// assert_eq!(sma_window.next(102.0), &[&100.2, &100.4, &100.8]);
-    /// assert_eq!(sma_window.next(102.0), &[&100.4, &100.8, &101.2]);
-    /// assert_eq!(sma_window.next(102.0), &[&100.8, &101.2, &101.6]);
+    /// assert_eq!(sma_window.next(100.0), [100.0, 100.0, 100.0]);
+    /// assert_eq!(sma_window.next(101.0), [100.0, 100.0, 100.2]);
+    /// assert_eq!(sma_window.next(101.0), [100.0, 100.2, 100.4]);
+    /// assert_eq!(sma_window.next(102.0), [100.2, 100.4, 100.8]);
+    /// assert_eq!(sma_window.next(102.0), [100.4, 100.8, 101.2]);
+    /// assert_eq!(sma_window.next(102.0), [100.8, 101.2, 101.6]);
     ///
     /// # }
     /// ```
-    fn window<'a>(self, window_size: usize) -> Window<'a, Self>
+    #[cfg(feature = "alloc")]
+    fn window(self, window_size: usize) -> Window<Self>
     where
-        Self: 'a,
+        Self::Output: Clone,
     {
         Window::new(self, window_size)
     }
+
+    /// Create a new indicator that applies a reducer to the past N output values ​​of the inner indicator.
+    ///
+    /// The window is padded with the oldest value as in [`window`](IndicatorExt::window),
+    /// and [`reducers`](crate::operators::reducers) provides sum, mean, min, max, argmax and product.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use indicator::*;
+    /// # fn main () {
+    /// use indicator::operators::{reducers, Identity};
+    ///
+    /// let sma = Sma::new(5).unwrap();
+    ///
+    /// let mut sma_max = sma.rolling(3, reducers::max);
+    ///
+    /// assert_eq!(sma_max.next(100.0), 100.0);
+    /// assert_eq!(sma_max.next(101.0), 100.2);
+    /// assert_eq!(sma_max.next(90.0), 100.2);
+    /// assert_eq!(sma_max.next(90.0), 100.2);
+    /// assert_eq!(sma_max.next(90.0), 98.2);
+    ///
+    /// let mut range = Identity::new().rolling(3, |w: &[f64]| reducers::max(w) - reducers::min(w));
+    ///
+    /// assert_eq!(range.next(2.0), 0.0);
+    /// assert_eq!(range.next(5.0), 3.0);
+    /// assert_eq!(range.next(1.0), 4.0);
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn rolling<F, R>(self, window_size: usize, f: F) -> Rolling<Self, F, R>
+    where
+        Self::Output: Clone,
+        F: FnMut(&[Self::Output]) -> R,
+    {
+        Window::new(self, window_size).reduce(f)
+    }
 }
 
 impl<I> IndicatorExt for I where I: Indicator + Sized {}
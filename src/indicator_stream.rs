@@ -27,13 +27,15 @@ where
     pub fn decompose(self) -> Inner {
         self.inner
     }
+}
 
-    unsafe fn pin_input_stream(&self) -> Pin<&mut InputStream> {
-        Pin::new_unchecked(&mut *(&self.input_stream as *const _ as *mut _))
-    }
-    unsafe fn inner_mut(&self) -> &mut Inner {
-        &mut *(&self.inner as *const _ as *mut _)
-    }
+// Neither field is structurally pinned: the input stream is `Unpin` and is only ever pinned in place
+// through `Pin::new`, so moving the adapter is fine whatever the indicator is.
+impl<Inner, InputStream> Unpin for IndicatorStream<Inner, InputStream>
+where
+    Inner: Indicator + Next<InputStream::Item>,
+    InputStream: Stream + Unpin,
+{
 }
 
 impl<Inner, InputStream> Stream for IndicatorStream<Inner, InputStream>
@@ -46,14 +48,11 @@ where
     fn poll_next(
         self: Pin<&mut Self>,
         cx: &mut core::task::Context<'_>,
-    ) -> std::task::Poll<Option<Self::Item>> {
-        let input_stream = unsafe { self.pin_input_stream() };
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
 
-        match input_stream.poll_next(cx) {
-            Poll::Ready(Some(input)) => {
-                let inner = unsafe { self.inner_mut() };
-                Poll::Ready(inner.next(input).into())
-            }
+        match Pin::new(&mut this.input_stream).poll_next(cx) {
+            Poll::Ready(Some(input)) => Poll::Ready(this.inner.next(input).into()),
             Poll::Ready(None) => Poll::Ready(None),
             Poll::Pending => Poll::Pending,
         }
@@ -0,0 +1,156 @@
+use crate::{
+    Close, Current, High, Indicator, Lookback, Low, Next, Reset, Volume, Warmup, WarmupPeriod,
+};
+
+/// Accumulation/Distribution Line
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccumulationDistribution {
+    current: Option<f64>,
+}
+impl AccumulationDistribution {
+    pub fn new() -> Self {
+        Self { current: None }
+    }
+
+    pub fn with_warmup<W: Warmup>(_warmup: W) -> W::Apply<Self> {
+        W::apply(Self::new())
+    }
+
+    fn _next(
+        &mut self,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    ) -> <Self as Indicator>::Output {
+        let money_flow_volume = money_flow_multiplier(high, low, close) * volume;
+        match &mut self.current {
+            Some(current) => {
+                *current += money_flow_volume;
+            }
+            None => {
+                self.current = money_flow_volume.into();
+            }
+        }
+        self.current().unwrap()
+    }
+}
+impl Default for AccumulationDistribution {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for AccumulationDistribution {
+    type Output = f64;
+}
+impl Current for AccumulationDistribution {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<(f64, f64, f64, f64)> for AccumulationDistribution {
+    fn next(&mut self, (high, low, close, volume): (f64, f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close, volume)
+    }
+}
+impl<Input: High + Low + Close + Volume> Next<&Input> for AccumulationDistribution {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close(), input.volume())
+    }
+}
+impl Reset for AccumulationDistribution {
+    fn reset(&mut self) {
+        self.current = None;
+    }
+}
+impl WarmupPeriod for AccumulationDistribution {
+    fn warmup_period(&self) -> usize {
+        0
+    }
+}
+impl Lookback for AccumulationDistribution {
+    fn is_ready(&self) -> bool {
+        self.current.is_some()
+    }
+}
+
+/// Money Flow Multiplier: `((Close - Low) - (High - Close)) / (High - Low)`
+///
+/// Range in \[-1.0, 1.0\]
+pub(crate) fn money_flow_multiplier(high: f64, low: f64, close: f64) -> f64 {
+    if high == low {
+        0.0
+    } else {
+        ((close - low) - (high - close)) / (high - low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+    impl Volume for TestItem {
+        fn volume(&self) -> f64 {
+            self.3
+        }
+    }
+
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0, 100.0),
+            (11.0, 9.0, 10.5, 150.0),
+            (12.0, 10.0, 11.0, 120.0),
+            (11.5, 9.5, 10.0, 200.0),
+            (10.0, 8.5, 9.0, 180.0),
+            (12.0, 9.0, 11.5, 250.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close, volume)| TestItem(high, low, close, volume))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[0.0, 75.0, 75.0, -25.0, -85.0, 81.66666667];
+
+    test_indicator! {
+        new: crate::Result::Ok(AccumulationDistribution::new()),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+        }
+    }
+}
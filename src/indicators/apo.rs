@@ -0,0 +1,187 @@
+use crate::{
+    Current, Indicator, Lookback, Macd, MacdOutput, Next, Price, Reset, Result, Warmup,
+    WarmupPeriod,
+};
+
+/// Absolute Price Oscillator
+///
+/// Difference between a short and a long [`Ema`](crate::Ema), with a signal line and a histogram.
+/// This is [`Macd`] under the name that some platforms give it.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Apo(Macd);
+impl Apo {
+    pub const DEFAULT_SHORT_PERIOD: usize = Macd::DEFAULT_SHORT_PERIOD;
+    pub const DEFAULT_LONG_PERIOD: usize = Macd::DEFAULT_LONG_PERIOD;
+    pub const DEFAULT_SIGNAL_PERIOD: usize = Macd::DEFAULT_SIGNAL_PERIOD;
+
+    pub fn new(short_period: usize, long_period: usize, signal_period: usize) -> Result<Self> {
+        Macd::new(short_period, long_period, signal_period).map(Self)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        short_period: usize,
+        long_period: usize,
+        signal_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Macd::with_seed(short_period, long_period, signal_period, W::SEED)
+            .map(Self)
+            .map(W::apply)
+    }
+}
+
+impl Indicator for Apo {
+    type Output = ApoOutput;
+}
+impl Current for Apo {
+    fn current(&self) -> Option<Self::Output> {
+        self.0.current().map(Into::into)
+    }
+}
+impl Next<f64> for Apo {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self.0.next(input).into()
+    }
+}
+impl<Input: Price> Next<&Input> for Apo {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self.0.next(input).into()
+    }
+}
+impl Reset for Apo {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+impl WarmupPeriod for Apo {
+    fn warmup_period(&self) -> usize {
+        self.0.warmup_period()
+    }
+}
+impl Lookback for Apo {
+    fn is_ready(&self) -> bool {
+        self.0.is_ready()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApoOutput {
+    pub apo: f64,
+    pub signal: f64,
+    pub histogram: f64,
+}
+impl From<MacdOutput> for ApoOutput {
+    fn from(
+        MacdOutput {
+            macd,
+            signal,
+            histogram,
+        }: MacdOutput,
+    ) -> Self {
+        Self {
+            apo: macd,
+            signal,
+            histogram,
+        }
+    }
+}
+impl From<(f64, f64, f64)> for ApoOutput {
+    fn from((apo, signal, histogram): (f64, f64, f64)) -> Self {
+        Self {
+            apo,
+            signal,
+            histogram,
+        }
+    }
+}
+impl From<ApoOutput> for (f64, f64, f64) {
+    fn from(x: ApoOutput) -> Self {
+        (x.apo, x.signal, x.histogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl Round for ApoOutput {
+        fn round(self) -> Self {
+            Self {
+                apo: Round::round(self.apo),
+                signal: Round::round(self.signal),
+                histogram: Round::round(self.histogram),
+            }
+        }
+    }
+
+    const SHORT_PERIOD: usize = 2;
+    const LONG_PERIOD: usize = 4;
+    const SIGNAL_PERIOD: usize = 2;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[ApoOutput]>> = SyncLazy::new(|| {
+        [
+            (0.0, 0.0, 0.0),
+            (0.53333333, 0.26666667, 0.26666667),
+            (0.23111111, 0.38222222, -0.15111111),
+            (0.90903704, 0.57007407, 0.33896296),
+            (0.53554568, 0.72229136, -0.18674568),
+            (1.11803523, 0.82679045, 0.29124477),
+            (0.66972374, 0.89387948, -0.22415574),
+        ]
+        .into_iter()
+        .map(ApoOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: Apo::new(SHORT_PERIOD, LONG_PERIOD, SIGNAL_PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    Apo::new(0, 2, 2),
+                    Apo::new(3, 3, 2),
+                    Apo::new(2, 3, 0),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Apo = Default::default();
+    }
+}
@@ -1,12 +1,13 @@
 use crate::{
-    Current, Indicator, InvalidRangeError, MaxIndex, MinIndex, Next, Parameter, Price, Range,
-    Reset, Result,
+    Current, Indicator, InvalidRangeError, Lookback, MaxIndex, MinIndex, Next, Parameter, Price,
+    Range, Reset, Result, Warmup, WarmupPeriod,
 };
 
 /// Aroon Indicator
 /// ///
 /// Range in \[0.0, 1.0\]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AroonIndicator {
     period: usize,
     max_index: MaxIndex,
@@ -33,6 +34,10 @@ impl AroonIndicator {
         }
     }
 
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
         let _ = self.min_index.next(input);
         let _ = self.max_index.next(input);
@@ -83,8 +88,19 @@ impl Reset for AroonIndicator {
         self.max_index.reset();
     }
 }
+impl WarmupPeriod for AroonIndicator {
+    fn warmup_period(&self) -> usize {
+        self.max_index.warmup_period()
+    }
+}
+impl Lookback for AroonIndicator {
+    fn is_ready(&self) -> bool {
+        self.max_index.is_ready() && self.min_index.is_ready()
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AroonIndicatorOutput {
     pub aroon_up: f64,
     pub aroon_down: f64,
@@ -97,9 +113,9 @@ impl From<(f64, f64)> for AroonIndicatorOutput {
         }
     }
 }
-impl Into<(f64, f64)> for AroonIndicatorOutput {
-    fn into(self) -> (f64, f64) {
-        (self.aroon_up, self.aroon_down)
+impl From<AroonIndicatorOutput> for (f64, f64) {
+    fn from(x: AroonIndicatorOutput) -> Self {
+        (x.aroon_up, x.aroon_down)
     }
 }
 
@@ -167,6 +183,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
 
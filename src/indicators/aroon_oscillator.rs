@@ -1,9 +1,12 @@
-use crate::{AroonIndicator, Current, Indicator, Next, Price, Reset, Result};
+use crate::{
+    AroonIndicator, Current, Indicator, Lookback, Next, Price, Reset, Result, Warmup, WarmupPeriod,
+};
 
 /// Aroon Oscillator
 /// ///
 /// Range in \[-1.0, 1.0\]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AroonOscillator {
     aroon_indicator: AroonIndicator,
 }
@@ -15,18 +18,15 @@ impl AroonOscillator {
         Ok(Self { aroon_indicator })
     }
 
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
         let _ = self.aroon_indicator.next(input);
         self.current().unwrap()
     }
 }
-impl Default for AroonOscillator {
-    fn default() -> Self {
-        Self {
-            aroon_indicator: Default::default(),
-        }
-    }
-}
 impl Indicator for AroonOscillator {
     type Output = f64;
 }
@@ -52,6 +52,16 @@ impl Reset for AroonOscillator {
         self.aroon_indicator.reset();
     }
 }
+impl WarmupPeriod for AroonOscillator {
+    fn warmup_period(&self) -> usize {
+        self.aroon_indicator.warmup_period()
+    }
+}
+impl Lookback for AroonOscillator {
+    fn is_ready(&self) -> bool {
+        self.aroon_indicator.is_ready()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -95,6 +105,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
 
@@ -0,0 +1,199 @@
+use crate::{
+    Close, Current, High, Indicator, Lookback, Low, Next, Reset, Result, Rma, Seed, TrueRange,
+    Warmup, WarmupPeriod,
+};
+
+/// Average True Range
+///
+/// Smoothed with [`Rma`] by default.
+/// Use [`Atr::with_moving_average`] to smooth with another moving average such as [`Ema`](crate::Ema) or [`Sma`](crate::Sma).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Atr<Ma = Rma> {
+    true_range: TrueRange,
+    ma: Ma,
+}
+impl Atr {
+    pub const DEFAULT_PERIOD: usize = 14;
+
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_seed(period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(period: usize, seed: Seed) -> Result<Self> {
+        let ma = Rma::with_seed(period, seed)?;
+        Ok(Self::with_moving_average(ma))
+    }
+}
+impl<Ma> Atr<Ma>
+where
+    Ma: Indicator<Output = f64> + Next<f64>,
+{
+    /// Create a new ATR smoothed with the given moving average.
+    pub fn with_moving_average(ma: Ma) -> Self {
+        Self {
+            true_range: TrueRange::new(),
+            ma,
+        }
+    }
+
+    fn _next(&mut self, high: f64, low: f64, close: f64) -> <Self as Indicator>::Output {
+        let true_range = self.true_range.next((high, low, close));
+        self.ma.next(true_range)
+    }
+}
+impl Default for Atr {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl<Ma> Indicator for Atr<Ma>
+where
+    Ma: Indicator<Output = f64>,
+{
+    type Output = f64;
+}
+impl<Ma> Current for Atr<Ma>
+where
+    Ma: Indicator<Output = f64> + Current,
+{
+    fn current(&self) -> Option<Self::Output> {
+        self.ma.current()
+    }
+}
+impl<Ma> Next<(f64, f64, f64)> for Atr<Ma>
+where
+    Ma: Indicator<Output = f64> + Next<f64>,
+{
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close)
+    }
+}
+impl<Ma, Input> Next<&Input> for Atr<Ma>
+where
+    Ma: Indicator<Output = f64> + Next<f64>,
+    Input: High + Low + Close,
+{
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close())
+    }
+}
+impl<Ma> Reset for Atr<Ma>
+where
+    Ma: Reset,
+{
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.ma.reset();
+    }
+}
+impl<Ma> WarmupPeriod for Atr<Ma>
+where
+    Ma: WarmupPeriod,
+{
+    fn warmup_period(&self) -> usize {
+        self.ma.warmup_period()
+    }
+}
+impl<Ma> Lookback for Atr<Ma>
+where
+    Ma: Lookback,
+{
+    fn is_ready(&self) -> bool {
+        self.ma.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::{test_helper::*, Ema};
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.0),
+            (12.0, 10.0, 11.0),
+            (15.0, 13.0, 14.0),
+            (13.0, 12.0, 12.5),
+            (12.0, 10.0, 11.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[2.0, 2.0, 2.0, 2.66666667, 2.44444444, 2.46296296];
+
+    test_indicator! {
+        new: Atr::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    Atr::new(0),
+                    Atr::new(1),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Atr = Default::default();
+    }
+
+    #[test]
+    fn with_moving_average() -> crate::Result<()> {
+        let mut true_range = TrueRange::new();
+        let mut ema = Ema::new(PERIOD)?;
+        let mut atr = Atr::with_moving_average(Ema::new(PERIOD)?);
+
+        for x in RANDOM_DATA.iter() {
+            let correct = ema.next(true_range.next(x));
+            assert_eq!(atr.next(x), correct);
+        }
+
+        Ok(())
+    }
+}
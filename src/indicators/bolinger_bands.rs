@@ -1,20 +1,52 @@
 use crate::{
-    Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result,
-    StandardDeviation,
+    Current, Indicator, InvalidRangeError, Lookback, Next, Parameter, Price, Range, Reset, Result,
+    StandardDeviation, Warmup, WarmupPeriod,
 };
 
 /// Bolinger Bands
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BolingerBands {
     sd: StandardDeviation,
     multiplier: f64,
+    price: Option<f64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BolingerBandsOutput {
     pub average: f64,
     pub upper_bound: f64,
     pub lower_bound: f64,
+    /// Position of the price within the bands (0.0 at the lower bound, 1.0 at the upper bound)
+    pub percent_b: f64,
+    /// Width of the bands relative to the average, or 0.0 while the average is 0.0
+    pub bandwidth: f64,
+}
+
+impl From<(f64, f64, f64, f64, f64)> for BolingerBandsOutput {
+    fn from(
+        (average, upper_bound, lower_bound, percent_b, bandwidth): (f64, f64, f64, f64, f64),
+    ) -> Self {
+        Self {
+            average,
+            upper_bound,
+            lower_bound,
+            percent_b,
+            bandwidth,
+        }
+    }
+}
+impl From<BolingerBandsOutput> for (f64, f64, f64, f64, f64) {
+    fn from(x: BolingerBandsOutput) -> Self {
+        (
+            x.average,
+            x.upper_bound,
+            x.lower_bound,
+            x.percent_b,
+            x.bandwidth,
+        )
+    }
 }
 
 impl BolingerBands {
@@ -29,13 +61,23 @@ impl BolingerBands {
         } else {
             Ok(Self {
                 sd,
-                multiplier: multiplier as f64,
+                multiplier,
+                price: None,
             })
         }
     }
 
+    pub fn with_warmup<W: Warmup>(
+        period: usize,
+        multiplier: f64,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::new(period, multiplier).map(W::apply)
+    }
+
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
         let _ = self.sd.next(input);
+        self.price = input.into();
         self.current().unwrap()
     }
 }
@@ -45,15 +87,20 @@ impl Indicator for BolingerBands {
 }
 impl Current for BolingerBands {
     fn current(&self) -> Option<Self::Output> {
-        if let Some(x) = self.sd.current() {
-            Self::Output {
-                average: x.mean,
-                upper_bound: x.mean + x.sd * self.multiplier,
-                lower_bound: x.mean - x.sd * self.multiplier,
+        match (self.sd.current(), self.price) {
+            (Some(x), Some(price)) => {
+                let upper_bound = x.mean + x.sd * self.multiplier;
+                let lower_bound = x.mean - x.sd * self.multiplier;
+                Self::Output {
+                    average: x.mean,
+                    upper_bound,
+                    lower_bound,
+                    percent_b: percent_b(price, upper_bound, lower_bound),
+                    bandwidth: bandwidth(x.mean, upper_bound, lower_bound),
+                }
+                .into()
             }
-            .into()
-        } else {
-            None
+            _ => None,
         }
     }
 }
@@ -70,6 +117,35 @@ impl<Input: Price> Next<&Input> for BolingerBands {
 impl Reset for BolingerBands {
     fn reset(&mut self) {
         self.sd.reset();
+        self.price = None;
+    }
+}
+impl WarmupPeriod for BolingerBands {
+    fn warmup_period(&self) -> usize {
+        self.sd.warmup_period()
+    }
+}
+impl Lookback for BolingerBands {
+    fn is_ready(&self) -> bool {
+        self.sd.is_ready()
+    }
+}
+
+/// `%B`: position of the price within the bands (0.0 at the lower bound, 1.0 at the upper bound)
+pub(crate) fn percent_b(price: f64, upper_bound: f64, lower_bound: f64) -> f64 {
+    if upper_bound == lower_bound {
+        0.5
+    } else {
+        (price - lower_bound) / (upper_bound - lower_bound)
+    }
+}
+
+/// Bandwidth: width of the bands relative to the average, or 0.0 while the average is 0.0
+pub(crate) fn bandwidth(average: f64, upper_bound: f64, lower_bound: f64) -> f64 {
+    if average == 0.0 {
+        0.0
+    } else {
+        (upper_bound - lower_bound) / average
     }
 }
 
@@ -93,6 +169,8 @@ mod tests {
                 average: Round::round(self.average),
                 upper_bound: Round::round(self.upper_bound),
                 lower_bound: Round::round(self.lower_bound),
+                percent_b: Round::round(self.percent_b),
+                bandwidth: Round::round(self.bandwidth),
             }
         }
     }
@@ -108,17 +186,13 @@ mod tests {
     });
     static OUTPUTS: SyncLazy<Box<[BolingerBandsOutput]>> = SyncLazy::new(|| {
         [
-            (100.0, 100.0, 100.0),
-            (100.8, 104.0, 97.6),
-            (101.2, 104.4, 98.0),
-            (101.6, 104.59332591, 98.60667409),
+            (100.0, 100.0, 100.0, 0.5, 0.0),
+            (100.8, 104.0, 97.6, 1.0, 0.06349206),
+            (101.2, 104.4, 98.0, 0.625, 0.06324111),
+            (101.6, 104.59332591, 98.60667409, 0.56681531, 0.05892374),
         ]
         .into_iter()
-        .map(|(average, upper_bound, lower_bound)| BolingerBandsOutput {
-            average,
-            upper_bound,
-            lower_bound,
-        })
+        .map(BolingerBandsOutput::from)
         .collect::<Vec<_>>()
         .into_boxed_slice()
     });
@@ -141,6 +215,23 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
+
+    #[test]
+    fn zero_average() -> crate::Result<()> {
+        // Around zero, as an oscillator is
+        let mut bands = BolingerBands::new(3, MULTIPLIER)?;
+
+        assert_eq!(bands.next(0.0).bandwidth, 0.0);
+        assert!(bands.next(1.0).bandwidth.is_finite());
+        let output = bands.next(-1.0);
+        assert_eq!(output.average, 0.0);
+        assert_eq!(output.bandwidth, 0.0);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,236 @@
+use crate::{
+    collections::OrderStatisticTree, Candlestick, Current, Indicator, InvalidRangeError, Lookback,
+    Next, Parameter, Range, Reset, Result, Warmup, WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Commodity Channel Index
+///
+/// `(typical price - mean) / (0.015 * mean absolute deviation)` over the last `period` typical prices.
+/// `Next<f64>` takes the typical price itself, so any other series can be fed as well.
+/// Outputs 0.0 while every value in the window is the same.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cci {
+    period: usize,
+    ring: VecDeque<f64>,
+    sorted: OrderStatisticTree,
+    count: usize,
+    current: Option<f64>,
+}
+impl Cci {
+    pub const DEFAULT_PERIOD: usize = 20;
+    /// Lambert's constant, which scales roughly 70-80% of the values into \[-100.0, 100.0\]
+    pub const CONSTANT: f64 = 0.015;
+
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                period,
+                ring: VecDeque::with_capacity(period),
+                sorted: OrderStatisticTree::with_capacity(period),
+                count: 0,
+                current: None,
+            })
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        if self.ring.is_empty() {
+            for _ in 0..self.period {
+                self.ring.push_back(input);
+                self.sorted.insert(input);
+            }
+        } else {
+            let old_val = self.ring.pop_front().unwrap();
+            let _ = self.sorted.remove(old_val);
+            self.ring.push_back(input);
+            self.sorted.insert(input);
+        }
+
+        // sum(|x - mean|) = (mean * below - sum(below)) + (sum(above) - mean * above)
+        let len = self.period as f64;
+        let sum = self.sorted.sum();
+        let mean = sum / len;
+        let (below_len, below_sum) = self.sorted.less_than(mean);
+        let below_len = below_len as f64;
+        let above_len = len - below_len;
+        let above_sum = sum - below_sum;
+        let mean_deviation =
+            (mean * below_len - below_sum + above_sum - mean * above_len).max(0.0) / len;
+
+        // A flat window must not amplify the rounding error of `mean`
+        let is_flat = self.sorted.get(0) == self.sorted.get(self.period - 1);
+        let cci = if is_flat || mean_deviation == 0.0 {
+            0.0
+        } else {
+            (input - mean) / (Self::CONSTANT * mean_deviation)
+        };
+        self.current = cci.into();
+        cci
+    }
+}
+impl Default for Cci {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for Cci {
+    type Output = f64;
+}
+impl Current for Cci {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for Cci {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Candlestick> Next<&Input> for Cci {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.hlc())
+    }
+}
+impl Reset for Cci {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ring.clear();
+        self.sorted.clear();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Cci {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl Lookback for Cci {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::{test_helper::*, Close, High, Low, Open, Volume};
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Open for TestItem {
+        fn open(&self) -> f64 {
+            0.0
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+    impl Volume for TestItem {
+        fn volume(&self) -> f64 {
+            0.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.5),
+            (12.0, 10.0, 11.0),
+            (11.5, 9.5, 10.0),
+            (10.0, 8.5, 9.0),
+            (12.0, 9.0, 11.5),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[0.0, 100.0, 89.47368421, -33.33333333, -100.0, 76.47058824];
+
+    test_indicator! {
+        new: Cci::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.hlc()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: Cci::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.hlc()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.hlc()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.hlc()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Cci = Default::default();
+    }
+
+    #[test]
+    fn compare_with_naive() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut cci = Cci::new(PERIOD)?;
+        let mut ring = VecDeque::new();
+
+        for x in RANDOM_DATA.iter() {
+            let tp = x.hlc();
+            if ring.is_empty() {
+                ring.extend([tp; PERIOD]);
+            } else {
+                ring.pop_front();
+                ring.push_back(tp);
+            }
+            let mean = ring.iter().sum::<f64>() / PERIOD as f64;
+            let mean_deviation = ring.iter().map(|y| (y - mean).abs()).sum::<f64>() / PERIOD as f64;
+            let is_flat = ring.iter().all(|&y| y == tp);
+            let correct = if is_flat || mean_deviation == 0.0 {
+                0.0
+            } else {
+                (tp - mean) / (Cci::CONSTANT * mean_deviation)
+            };
+
+            assert!((cci.next(x) - correct).abs() < PRECISION * 1e3);
+        }
+
+        Ok(())
+    }
+}
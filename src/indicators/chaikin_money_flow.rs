@@ -0,0 +1,155 @@
+use crate::indicators::accumulation_distribution::money_flow_multiplier;
+use crate::{
+    Close, Current, High, Indicator, Lookback, Low, Next, Reset, Result, Volume, Vwma, Warmup,
+    WarmupPeriod,
+};
+
+/// Chaikin Money Flow
+///
+/// Range in \[-1.0, 1.0\]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChaikinMoneyFlow {
+    vwma: Vwma,
+}
+impl ChaikinMoneyFlow {
+    pub const DEFAULT_PERIOD: usize = 20;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let vwma = Vwma::new(period)?;
+        Ok(Self { vwma })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(
+        &mut self,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    ) -> <Self as Indicator>::Output {
+        // The volume weighted average of the money flow multiplier equals
+        // the sum of the money flow volume divided by the sum of the volume.
+        self.vwma
+            .next((money_flow_multiplier(high, low, close), volume))
+    }
+}
+impl Default for ChaikinMoneyFlow {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for ChaikinMoneyFlow {
+    type Output = f64;
+}
+impl Current for ChaikinMoneyFlow {
+    fn current(&self) -> Option<Self::Output> {
+        self.vwma.current()
+    }
+}
+impl Next<(f64, f64, f64, f64)> for ChaikinMoneyFlow {
+    fn next(&mut self, (high, low, close, volume): (f64, f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close, volume)
+    }
+}
+impl<Input: High + Low + Close + Volume> Next<&Input> for ChaikinMoneyFlow {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close(), input.volume())
+    }
+}
+impl Reset for ChaikinMoneyFlow {
+    fn reset(&mut self) {
+        self.vwma.reset();
+    }
+}
+impl WarmupPeriod for ChaikinMoneyFlow {
+    fn warmup_period(&self) -> usize {
+        self.vwma.warmup_period()
+    }
+}
+impl Lookback for ChaikinMoneyFlow {
+    fn is_ready(&self) -> bool {
+        self.vwma.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+    impl Volume for TestItem {
+        fn volume(&self) -> f64 {
+            self.3
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0, 100.0),
+            (11.0, 9.0, 10.5, 150.0),
+            (12.0, 10.0, 11.0, 120.0),
+            (11.5, 9.5, 10.0, 200.0),
+            (10.0, 8.5, 9.0, 180.0),
+            (12.0, 9.0, 11.5, 250.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close, volume)| TestItem(high, low, close, volume))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[0.0, 0.21428571, 0.2027027, -0.05319149, -0.32, 0.01058201];
+
+    test_indicator! {
+        new: ChaikinMoneyFlow::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: ChaikinMoneyFlow::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: ChaikinMoneyFlow = Default::default();
+    }
+}
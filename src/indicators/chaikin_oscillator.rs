@@ -0,0 +1,193 @@
+use crate::operators::Diff;
+use crate::{
+    AccumulationDistribution, Close, Current, Ema, High, Indicator, InvalidBinaryRelationError,
+    Lookback, Low, Next, Parameter, Reset, Result, Seed, Volume, Warmup, WarmupPeriod,
+};
+
+/// Chaikin Oscillator
+///
+/// Difference between the fast and slow EMA of the Accumulation/Distribution Line
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChaikinOscillator {
+    accumulation_distribution: AccumulationDistribution,
+    oscillator: Diff<Ema, Ema>,
+}
+impl ChaikinOscillator {
+    pub const DEFAULT_FAST_PERIOD: usize = 3;
+    pub const DEFAULT_SLOW_PERIOD: usize = 10;
+
+    pub fn new(fast_period: usize, slow_period: usize) -> Result<Self> {
+        Self::with_seed(fast_period, slow_period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        fast_period: usize,
+        slow_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(fast_period, slow_period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(fast_period: usize, slow_period: usize, seed: Seed) -> Result<Self> {
+        if fast_period >= slow_period {
+            return Err(InvalidBinaryRelationError {
+                operator: "<",
+                lhs: Parameter::new("fast_period", fast_period),
+                rhs: Parameter::new("slow_period", slow_period),
+            }
+            .into());
+        }
+
+        Ok(Self {
+            accumulation_distribution: AccumulationDistribution::new(),
+            oscillator: Diff::new(
+                Ema::with_seed(fast_period, seed)?,
+                Ema::with_seed(slow_period, seed)?,
+            ),
+        })
+    }
+
+    fn _next(
+        &mut self,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    ) -> <Self as Indicator>::Output {
+        let ad = self
+            .accumulation_distribution
+            .next((high, low, close, volume));
+        self.oscillator.next((ad, ad))
+    }
+}
+impl Default for ChaikinOscillator {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_FAST_PERIOD, Self::DEFAULT_SLOW_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for ChaikinOscillator {
+    type Output = f64;
+}
+impl Current for ChaikinOscillator {
+    fn current(&self) -> Option<Self::Output> {
+        self.oscillator.current()
+    }
+}
+impl Next<(f64, f64, f64, f64)> for ChaikinOscillator {
+    fn next(&mut self, (high, low, close, volume): (f64, f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close, volume)
+    }
+}
+impl<Input: High + Low + Close + Volume> Next<&Input> for ChaikinOscillator {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close(), input.volume())
+    }
+}
+impl Reset for ChaikinOscillator {
+    fn reset(&mut self) {
+        self.accumulation_distribution.reset();
+        self.oscillator.reset();
+    }
+}
+impl WarmupPeriod for ChaikinOscillator {
+    fn warmup_period(&self) -> usize {
+        // The slow EMA is the last to warm up
+        self.oscillator.rhs().warmup_period()
+    }
+}
+impl Lookback for ChaikinOscillator {
+    fn is_ready(&self) -> bool {
+        self.oscillator.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+    impl Volume for TestItem {
+        fn volume(&self) -> f64 {
+            self.3
+        }
+    }
+
+    const FAST_PERIOD: usize = 2;
+    const SLOW_PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0, 100.0),
+            (11.0, 9.0, 10.5, 150.0),
+            (12.0, 10.0, 11.0, 120.0),
+            (11.5, 9.5, 10.0, 200.0),
+            (10.0, 8.5, 9.0, 180.0),
+            (12.0, 9.0, 11.5, 250.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close, volume)| TestItem(high, low, close, volume))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        0.0,
+        20.0,
+        18.66666667,
+        -13.24444444,
+        -32.09481481,
+        17.13817284,
+    ];
+
+    test_indicator! {
+        new: ChaikinOscillator::new(FAST_PERIOD, SLOW_PERIOD),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    ChaikinOscillator::new(0, 2),
+                    ChaikinOscillator::new(2, 0),
+                    ChaikinOscillator::new(2, 2),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: ChaikinOscillator = Default::default();
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    Current, Indicator, Lookback, Next, PercentRank, Price, Reset, Result, Rsi, Seed, Warmup,
+    WarmupPeriod,
+};
+
+/// Connors RSI
+///
+/// Average of the [`Rsi`] of the price, the [`Rsi`] of the up/down streak length
+/// and the [`PercentRank`] of the 1-bar rate of change among the previous `rank_period` ones.
+/// The rate of change is taken as 0.0 when the previous price is 0.0, as it is undefined there.
+///
+/// Range in \[0.0, 1.0\]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConnorsRsi {
+    rsi: Rsi,
+    streak_rsi: Rsi,
+    roc_rank: PercentRank,
+    streak: f64,
+    prev_input: Option<f64>,
+    count: usize,
+    current: Option<f64>,
+}
+impl ConnorsRsi {
+    pub const DEFAULT_RSI_PERIOD: usize = 3;
+    pub const DEFAULT_STREAK_PERIOD: usize = 2;
+    pub const DEFAULT_RANK_PERIOD: usize = 100;
+
+    pub fn new(rsi_period: usize, streak_period: usize, rank_period: usize) -> Result<Self> {
+        Self::with_seed(rsi_period, streak_period, rank_period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        rsi_period: usize,
+        streak_period: usize,
+        rank_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(rsi_period, streak_period, rank_period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(
+        rsi_period: usize,
+        streak_period: usize,
+        rank_period: usize,
+        seed: Seed,
+    ) -> Result<Self> {
+        let rsi = Rsi::with_seed(rsi_period, seed)?;
+        let streak_rsi = Rsi::with_seed(streak_period, seed)?;
+        let roc_rank = PercentRank::new(rank_period)?;
+
+        Ok(Self {
+            rsi,
+            streak_rsi,
+            roc_rank,
+            streak: 0.0,
+            prev_input: None,
+            count: 0,
+            current: None,
+        })
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let rsi = self.rsi.next(input);
+
+        let roc = match self.prev_input {
+            Some(prev_input) => {
+                self.streak = match input {
+                    input if prev_input < input => self.streak.max(0.0) + 1.0,
+                    input if input < prev_input => self.streak.min(0.0) - 1.0,
+                    _ => 0.0,
+                };
+                if prev_input == 0.0 {
+                    0.0
+                } else {
+                    (input - prev_input) / prev_input
+                }
+            }
+            None => 0.0,
+        };
+        self.prev_input = input.into();
+        let streak_rsi = self.streak_rsi.next(self.streak);
+
+        let percent_rank = self.roc_rank.next(roc);
+
+        let connors_rsi = (rsi + streak_rsi + percent_rank) / 3.0;
+        self.current = connors_rsi.into();
+        connors_rsi
+    }
+}
+impl Default for ConnorsRsi {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_RSI_PERIOD,
+            Self::DEFAULT_STREAK_PERIOD,
+            Self::DEFAULT_RANK_PERIOD,
+        )
+        .unwrap()
+    }
+}
+
+impl Indicator for ConnorsRsi {
+    type Output = f64;
+}
+impl Current for ConnorsRsi {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for ConnorsRsi {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for ConnorsRsi {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for ConnorsRsi {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.rsi.reset();
+        self.streak_rsi.reset();
+        self.roc_rank.reset();
+        self.streak = 0.0;
+        self.prev_input = None;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for ConnorsRsi {
+    fn warmup_period(&self) -> usize {
+        // Rates of change start from the second input
+        let roc_rank = self.roc_rank.warmup_period() + 1;
+        roc_rank
+            .max(self.rsi.warmup_period())
+            .max(self.streak_rsi.warmup_period())
+    }
+}
+impl Lookback for ConnorsRsi {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const RSI_PERIOD: usize = 3;
+    const STREAK_PERIOD: usize = 2;
+    const RANK_PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 102.0, 101.0, 100.0, 99.0, 101.0, 103.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        0.33333333, 1.0, 0.91666667, 0.24210526, 0.14604236, 0.08883218, 0.80259305, 0.79507903,
+    ];
+
+    test_indicator! {
+        new: ConnorsRsi::new(RSI_PERIOD, STREAK_PERIOD, RANK_PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    ConnorsRsi::new(1, 2, 1),
+                    ConnorsRsi::new(2, 1, 1),
+                    ConnorsRsi::new(2, 2, 0),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: ConnorsRsi = Default::default();
+    }
+
+    #[test]
+    fn zero_prev_input() -> crate::Result<()> {
+        let mut connors_rsi = ConnorsRsi::new(RSI_PERIOD, STREAK_PERIOD, RANK_PERIOD)?;
+
+        let _ = connors_rsi.next(0.0);
+        assert!(connors_rsi.next(0.0).is_finite());
+        assert!(connors_rsi.next(1.0).is_finite());
+        // Outranks the 0.0 rates of change above, where NaN and infinity would not be outranked
+        let _ = connors_rsi.next(2.0);
+        assert_eq!(connors_rsi.roc_rank.current(), Some(1.0));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    Current, Ema, Indicator, Lookback, Next, Price, Reset, Result, Seed, Warmup, WarmupPeriod,
+};
+
+/// Double Exponential Moving Average
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dema {
+    ema: Ema,
+    ema_ema: Ema,
+    count: usize,
+}
+impl Dema {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_seed(period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(period: usize, seed: Seed) -> Result<Self> {
+        Ok(Self {
+            ema: Ema::with_seed(period, seed)?,
+            ema_ema: Ema::with_seed(period, seed)?,
+            count: 0,
+        })
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let ema = self.ema.next(input);
+        let _ = self.ema_ema.next(ema);
+        self.current().unwrap()
+    }
+}
+
+impl Indicator for Dema {
+    type Output = f64;
+}
+impl Current for Dema {
+    fn current(&self) -> Option<Self::Output> {
+        match (self.ema.current(), self.ema_ema.current()) {
+            (Some(ema), Some(ema_ema)) => (2.0 * ema - ema_ema).into(),
+            _ => None,
+        }
+    }
+}
+impl Next<f64> for Dema {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Dema {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Dema {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ema.reset();
+        self.ema_ema.reset();
+    }
+}
+impl WarmupPeriod for Dema {
+    fn warmup_period(&self) -> usize {
+        self.ema.warmup_period() + self.ema_ema.warmup_period()
+    }
+}
+impl Lookback for Dema {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[100.0, 100.75, 102.5, 102.3125, 103.6875, 106.359375];
+
+    test_indicator! {
+        new: Dema::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: Dema::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{
+    Atr, Close, Current, High, Indicator, Lookback, Low, Next, Reset, Result, Rma, Seed, Warmup,
+    WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Directional Movement Index (+DI, -DI, DX, ADX and ADXR)
+///
+/// All values range in \[0.0, 1.0\]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dmi {
+    adx_period: usize,
+    atr: Atr,
+    plus_dm: Rma,
+    minus_dm: Rma,
+    adx: Rma,
+    adx_ring: VecDeque<f64>,
+    prev_high_low: Option<(f64, f64)>,
+    count: usize,
+    current: Option<DmiOutput>,
+}
+impl Dmi {
+    pub const DEFAULT_DI_PERIOD: usize = 14;
+    pub const DEFAULT_ADX_PERIOD: usize = 14;
+
+    pub fn new(di_period: usize, adx_period: usize) -> Result<Self> {
+        Self::with_seed(di_period, adx_period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        di_period: usize,
+        adx_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(di_period, adx_period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(di_period: usize, adx_period: usize, seed: Seed) -> Result<Self> {
+        let atr = Atr::with_seed(di_period, seed)?;
+        let plus_dm = Rma::with_seed(di_period, seed)?;
+        let minus_dm = Rma::with_seed(di_period, seed)?;
+        let adx = Rma::with_seed(adx_period, seed)?;
+        Ok(Self {
+            adx_period,
+            atr,
+            plus_dm,
+            minus_dm,
+            adx,
+            adx_ring: VecDeque::with_capacity(adx_period),
+            prev_high_low: None,
+            count: 0,
+            current: None,
+        })
+    }
+
+    fn _next(&mut self, high: f64, low: f64, close: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let (plus_dm, minus_dm) = match self.prev_high_low {
+            Some((prev_high, prev_low)) => {
+                let up_move = high - prev_high;
+                let down_move = prev_low - low;
+                (
+                    if down_move < up_move && 0.0 < up_move {
+                        up_move
+                    } else {
+                        0.0
+                    },
+                    if up_move < down_move && 0.0 < down_move {
+                        down_move
+                    } else {
+                        0.0
+                    },
+                )
+            }
+            None => (0.0, 0.0),
+        };
+        self.prev_high_low = (high, low).into();
+
+        let atr = self.atr.next((high, low, close));
+        let plus_dm = self.plus_dm.next(plus_dm);
+        let minus_dm = self.minus_dm.next(minus_dm);
+
+        let (plus_di, minus_di) = if atr == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (plus_dm / atr, minus_dm / atr)
+        };
+        let dx = if plus_di + minus_di == 0.0 {
+            0.0
+        } else {
+            (plus_di - minus_di).abs() / (plus_di + minus_di)
+        };
+        let adx = self.adx.next(dx);
+
+        if self.adx_ring.is_empty() {
+            for _ in 0..self.adx_period {
+                self.adx_ring.push_back(adx);
+            }
+        } else {
+            let _ = self.adx_ring.pop_front();
+            self.adx_ring.push_back(adx);
+        }
+        let adxr = (adx + self.adx_ring.front().unwrap()) / 2.0;
+
+        self.current = DmiOutput {
+            plus_di,
+            minus_di,
+            dx,
+            adx,
+            adxr,
+        }
+        .into();
+        self.current().unwrap()
+    }
+}
+impl Default for Dmi {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DI_PERIOD, Self::DEFAULT_ADX_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for Dmi {
+    type Output = DmiOutput;
+}
+impl Current for Dmi {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<(f64, f64, f64)> for Dmi {
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close)
+    }
+}
+impl<Input: High + Low + Close> Next<&Input> for Dmi {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close())
+    }
+}
+impl Reset for Dmi {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.atr.reset();
+        self.plus_dm.reset();
+        self.minus_dm.reset();
+        self.adx.reset();
+        self.adx_ring.clear();
+        self.prev_high_low = None;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Dmi {
+    fn warmup_period(&self) -> usize {
+        // Directional movement starts from the second bar,
+        // and ADXR also looks back `adx_period - 1` bars of ADX
+        1 + self.plus_dm.warmup_period() + self.adx.warmup_period() + self.adx_period - 1
+    }
+}
+impl Lookback for Dmi {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DmiOutput {
+    pub plus_di: f64,
+    pub minus_di: f64,
+    pub dx: f64,
+    pub adx: f64,
+    pub adxr: f64,
+}
+impl From<(f64, f64, f64, f64, f64)> for DmiOutput {
+    fn from((plus_di, minus_di, dx, adx, adxr): (f64, f64, f64, f64, f64)) -> Self {
+        Self {
+            plus_di,
+            minus_di,
+            dx,
+            adx,
+            adxr,
+        }
+    }
+}
+impl From<DmiOutput> for (f64, f64, f64, f64, f64) {
+    fn from(x: DmiOutput) -> Self {
+        (x.plus_di, x.minus_di, x.dx, x.adx, x.adxr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for DmiOutput {
+        fn round(self) -> Self {
+            Self {
+                plus_di: Round::round(self.plus_di),
+                minus_di: Round::round(self.minus_di),
+                dx: Round::round(self.dx),
+                adx: Round::round(self.adx),
+                adxr: Round::round(self.adxr),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+
+    const DI_PERIOD: usize = 3;
+    const ADX_PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.0),
+            (12.0, 10.0, 11.0),
+            (11.5, 9.5, 10.0),
+            (10.0, 8.5, 9.0),
+            (12.0, 9.0, 11.5),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[DmiOutput]>> = SyncLazy::new(|| {
+        [
+            (0.0, 0.0, 0.0, 0.0, 0.0),
+            (0.16666667, 0.0, 1.0, 0.33333333, 0.16666667),
+            (0.27777778, 0.0, 1.0, 0.55555556, 0.27777778),
+            (0.18518519, 0.08333333, 0.37931034, 0.49680715, 0.41507024),
+            (0.13468013, 0.24242424, 0.28571429, 0.42644286, 0.49099921),
+            (0.37407407, 0.13333333, 0.47445255, 0.44244609, 0.46962662),
+        ]
+        .into_iter()
+        .map(DmiOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: Dmi::new(DI_PERIOD, ADX_PERIOD),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    Dmi::new(0, 2),
+                    Dmi::new(1, 2),
+                    Dmi::new(2, 0),
+                    Dmi::new(2, 1),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Dmi = Default::default();
+    }
+}
@@ -0,0 +1,227 @@
+use crate::indicators::bolinger_bands::{bandwidth, percent_b};
+use crate::{
+    Close, Current, High, Indicator, Lookback, Low, Max, Min, Next, Reset, Result, Warmup,
+    WarmupPeriod,
+};
+
+/// Donchian Channels
+///
+/// Highest high and lowest low over the period
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DonchianChannels {
+    max: Max,
+    min: Min,
+    close: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DonchianChannelsOutput {
+    pub average: f64,
+    pub upper_bound: f64,
+    pub lower_bound: f64,
+    /// Position of the close within the channels (0.0 at the lower bound, 1.0 at the upper bound)
+    pub percent_b: f64,
+    /// Width of the channels relative to the average
+    pub bandwidth: f64,
+}
+impl From<(f64, f64, f64, f64, f64)> for DonchianChannelsOutput {
+    fn from(
+        (average, upper_bound, lower_bound, percent_b, bandwidth): (f64, f64, f64, f64, f64),
+    ) -> Self {
+        Self {
+            average,
+            upper_bound,
+            lower_bound,
+            percent_b,
+            bandwidth,
+        }
+    }
+}
+impl From<DonchianChannelsOutput> for (f64, f64, f64, f64, f64) {
+    fn from(x: DonchianChannelsOutput) -> Self {
+        (
+            x.average,
+            x.upper_bound,
+            x.lower_bound,
+            x.percent_b,
+            x.bandwidth,
+        )
+    }
+}
+
+impl DonchianChannels {
+    pub const DEFAULT_PERIOD: usize = 20;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let max = Max::new(period)?;
+        let min = Min::new(period)?;
+        Ok(Self {
+            max,
+            min,
+            close: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, high: f64, low: f64, close: f64) -> <Self as Indicator>::Output {
+        let _ = self.max.next(high);
+        let _ = self.min.next(low);
+        self.close = close.into();
+        self.current().unwrap()
+    }
+}
+impl Default for DonchianChannels {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for DonchianChannels {
+    type Output = DonchianChannelsOutput;
+}
+impl Current for DonchianChannels {
+    fn current(&self) -> Option<Self::Output> {
+        match (self.max.current(), self.min.current(), self.close) {
+            (Some(upper_bound), Some(lower_bound), Some(close)) => {
+                let average = (upper_bound + lower_bound) / 2.0;
+                Self::Output {
+                    average,
+                    upper_bound,
+                    lower_bound,
+                    percent_b: percent_b(close, upper_bound, lower_bound),
+                    bandwidth: bandwidth(average, upper_bound, lower_bound),
+                }
+                .into()
+            }
+            _ => None,
+        }
+    }
+}
+impl Next<(f64, f64, f64)> for DonchianChannels {
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close)
+    }
+}
+impl<Input: High + Low + Close> Next<&Input> for DonchianChannels {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close())
+    }
+}
+impl Reset for DonchianChannels {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+        self.close = None;
+    }
+}
+impl WarmupPeriod for DonchianChannels {
+    fn warmup_period(&self) -> usize {
+        self.max.warmup_period()
+    }
+}
+impl Lookback for DonchianChannels {
+    fn is_ready(&self) -> bool {
+        self.max.is_ready() && self.min.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for DonchianChannelsOutput {
+        fn round(self) -> Self {
+            Self {
+                average: Round::round(self.average),
+                upper_bound: Round::round(self.upper_bound),
+                lower_bound: Round::round(self.lower_bound),
+                percent_b: Round::round(self.percent_b),
+                bandwidth: Round::round(self.bandwidth),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.0),
+            (12.0, 10.0, 11.0),
+            (15.0, 13.0, 14.0),
+            (13.0, 12.0, 12.5),
+            (12.0, 10.0, 11.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[DonchianChannelsOutput]>> = SyncLazy::new(|| {
+        [
+            (9.0, 10.0, 8.0, 0.5, 0.22222222),
+            (9.5, 11.0, 8.0, 0.66666667, 0.31578947),
+            (10.0, 12.0, 8.0, 0.75, 0.4),
+            (12.0, 15.0, 9.0, 0.83333333, 0.5),
+            (12.5, 15.0, 10.0, 0.5, 0.4),
+            (12.5, 15.0, 10.0, 0.2, 0.4),
+        ]
+        .into_iter()
+        .map(DonchianChannelsOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: DonchianChannels::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: DonchianChannels::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: DonchianChannels = Default::default();
+    }
+}
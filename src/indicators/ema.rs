@@ -1,13 +1,33 @@
-use crate::{Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result};
+use crate::{
+    Current, Indicator, InvalidRangeError, Lookback, Next, Number, Parameter, Price, Range, Reset,
+    Result, Seed, Warmup, WarmupPeriod,
+};
 
 /// Exponential Moving Average
 #[derive(Debug, Clone)]
-pub struct Ema {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ema<T = f64> {
     period: usize,
-    current: Option<f64>,
+    seed: Seed,
+    count: usize,
+    current: Option<T>,
 }
 impl Ema {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, W::SEED).map(W::apply)
+    }
+}
+impl<T: Number> Ema<T> {
+    /// Same as `new`, in any [`Number`] type
+    pub fn with_period(period: usize) -> Result<Self> {
+        Self::with_seed(period, Seed::First)
+    }
+
+    pub(crate) fn with_seed(period: usize, seed: Seed) -> Result<Self> {
         if period < 1 {
             Err(InvalidRangeError {
                 param: Parameter::new("period", period),
@@ -17,15 +37,23 @@ impl Ema {
         } else {
             Ok(Self {
                 period,
+                seed,
+                count: 0,
                 current: None,
             })
         }
     }
 
-    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+    fn _next(&mut self, input: T) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         match &mut self.current {
             Some(current) => {
-                *current += (input - *current) * (2.0 / (self.period + 1) as f64);
+                // Seeded with the SMA, the first `period` inputs are averaged with equal weights
+                let alpha = match self.seed {
+                    Seed::Mean if self.count <= self.period => T::ONE / T::from_usize(self.count),
+                    _ => T::from_usize(2) / T::from_usize(self.period + 1),
+                };
+                *current += (input - *current) * alpha;
             }
             None => {
                 self.current = input.into();
@@ -35,36 +63,43 @@ impl Ema {
     }
 }
 
-impl Indicator for Ema {
-    type Output = f64;
+impl<T: Number> Indicator for Ema<T> {
+    type Output = T;
 }
-impl Current for Ema {
+impl<T: Number> Current for Ema<T> {
     fn current(&self) -> Option<Self::Output> {
         self.current
     }
 }
-impl Next<f64> for Ema {
-    fn next(&mut self, input: f64) -> Self::Output {
-        self._next(input)
-    }
-}
-impl<Input: Price> Next<&Input> for Ema {
+impl_next_for_numbers!(Ema);
+impl<T: Number, Input: Price<T>> Next<&Input> for Ema<T> {
     fn next(&mut self, input: &Input) -> Self::Output {
         self._next(input.price())
     }
 }
-impl Reset for Ema {
+impl<T: Number> Reset for Ema<T> {
     fn reset(&mut self) {
+        self.count = 0;
         self.current = None;
     }
 }
+impl<T: Number> WarmupPeriod for Ema<T> {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl<T: Number> Lookback for Ema<T> {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use once_cell::sync::Lazy as SyncLazy;
 
     use super::*;
-    use crate::test_helper::*;
+    use crate::{test_helper::*, SmaSeed};
 
     #[derive(Clone)]
     struct TestItem(f64);
@@ -102,6 +137,30 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn sma_seed() -> crate::Result<()> {
+        const PERIOD: usize = 10;
+        let mut ema = Ema::with_warmup(PERIOD, SmaSeed)?;
+
+        let inputs: Vec<f64> = RANDOM_DATA.iter().map(|x| x.price()).collect();
+        let mut correct = inputs[..PERIOD].iter().sum::<f64>() / PERIOD as f64;
+        for (i, x) in inputs.iter().enumerate() {
+            let value = ema.next(*x);
+            if i < PERIOD {
+                let mean = inputs[..=i].iter().sum::<f64>() / (i + 1) as f64;
+                assert!((value - mean).abs() < PRECISION);
+            } else {
+                correct += (x - correct) * 2.0 / (PERIOD + 1) as f64;
+                assert!((value - correct).abs() < PRECISION);
+            }
         }
+
+        Ok(())
     }
 }
@@ -0,0 +1,241 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    Current, Indicator, InvalidRangeError, Lookback, Max, Min, Next, Parameter, Price, Range,
+    Reset, Result, Warmup, WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Fractal Adaptive Moving Average
+///
+/// The smoothing constant is derived from the fractal dimension of the window.
+/// An odd period is rounded down to an even number, since the window is split into two halves.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frama {
+    half_period: usize,
+    recent_max: Max,
+    recent_min: Min,
+    older_max: Max,
+    older_min: Min,
+    ring: VecDeque<f64>,
+    count: usize,
+    current: Option<FramaOutput>,
+}
+impl Frama {
+    pub const DEFAULT_PERIOD: usize = 16;
+    pub const MIN_ALPHA: f64 = 0.01;
+
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 2 },
+            }
+            .into());
+        }
+
+        let half_period = period / 2;
+        Ok(Self {
+            half_period,
+            recent_max: Max::new(half_period)?,
+            recent_min: Min::new(half_period)?,
+            older_max: Max::new(half_period)?,
+            older_min: Min::new(half_period)?,
+            ring: VecDeque::with_capacity(half_period + 1),
+            count: 0,
+            current: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        // `ring` delays the inputs by half a period so that they can be fed to the older half.
+        if self.ring.is_empty() {
+            for _ in 0..self.half_period {
+                self.ring.push_back(input);
+            }
+        }
+        self.ring.push_back(input);
+        let delayed_input = self.ring.pop_front().unwrap();
+
+        let recent_max = self.recent_max.next(input);
+        let recent_min = self.recent_min.next(input);
+        let older_max = self.older_max.next(delayed_input);
+        let older_min = self.older_min.next(delayed_input);
+
+        let half_period = self.half_period as f64;
+        let n1 = (older_max - older_min) / half_period;
+        let n2 = (recent_max - recent_min) / half_period;
+        let n3 = (recent_max.max(older_max) - recent_min.min(older_min)) / (2.0 * half_period);
+
+        let dimension = if 0.0 < n1 + n2 && 0.0 < n3 {
+            ((n1 + n2).ln() - n3.ln()) / core::f64::consts::LN_2
+        } else {
+            1.0
+        };
+        let alpha = (-4.6 * (dimension - 1.0)).exp().clamp(Self::MIN_ALPHA, 1.0);
+
+        match &mut self.current {
+            Some(current) => {
+                current.frama += (input - current.frama) * alpha;
+                current.alpha = alpha;
+            }
+            None => {
+                self.current = FramaOutput {
+                    frama: input,
+                    alpha,
+                }
+                .into();
+            }
+        }
+        self.current().unwrap()
+    }
+}
+impl Default for Frama {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FramaOutput {
+    pub frama: f64,
+    /// Smoothing constant derived from the fractal dimension
+    pub alpha: f64,
+}
+impl From<(f64, f64)> for FramaOutput {
+    fn from((frama, alpha): (f64, f64)) -> Self {
+        Self { frama, alpha }
+    }
+}
+impl From<FramaOutput> for (f64, f64) {
+    fn from(x: FramaOutput) -> Self {
+        (x.frama, x.alpha)
+    }
+}
+
+impl Indicator for Frama {
+    type Output = FramaOutput;
+}
+impl Current for Frama {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for Frama {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Frama {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Frama {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.recent_max.reset();
+        self.recent_min.reset();
+        self.older_max.reset();
+        self.older_min.reset();
+        self.ring.clear();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Frama {
+    fn warmup_period(&self) -> usize {
+        // The older half is full once the delayed inputs have passed through it
+        self.older_max.warmup_period() + self.half_period
+    }
+}
+impl Lookback for Frama {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for FramaOutput {
+        fn round(self) -> Self {
+            Self {
+                frama: Round::round(self.frama),
+                alpha: Round::round(self.alpha),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0, 106.0, 108.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[FramaOutput]>> = SyncLazy::new(|| {
+        [
+            (100.00000000, 1.00000000),
+            (101.00000000, 1.00000000),
+            (103.00000000, 1.00000000),
+            (102.00000000, 1.00000000),
+            (102.29640595, 0.14820297),
+            (107.00000000, 1.00000000),
+            (106.00000000, 1.00000000),
+            (106.45487981, 0.22743990),
+        ]
+        .into_iter()
+        .map(FramaOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: Frama::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [Frama::new(0), Frama::new(1)],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Frama = Default::default();
+    }
+}
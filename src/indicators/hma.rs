@@ -0,0 +1,143 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    Current, Indicator, InvalidRangeError, Lookback, Next, Parameter, Price, Range, Reset, Result,
+    Warmup, WarmupPeriod, Wma,
+};
+
+/// Hull Moving Average
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hma {
+    half_wma: Wma,
+    full_wma: Wma,
+    sqrt_wma: Wma,
+    count: usize,
+}
+impl Hma {
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 2 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                half_wma: Wma::new(period / 2)?,
+                full_wma: Wma::new(period)?,
+                sqrt_wma: Wma::new((period as f64).sqrt() as usize)?,
+                count: 0,
+            })
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let half = self.half_wma.next(input);
+        let full = self.full_wma.next(input);
+        self.sqrt_wma.next(2.0 * half - full)
+    }
+}
+
+impl Indicator for Hma {
+    type Output = f64;
+}
+impl Current for Hma {
+    fn current(&self) -> Option<Self::Output> {
+        self.sqrt_wma.current()
+    }
+}
+impl Next<f64> for Hma {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Hma {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Hma {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.half_wma.reset();
+        self.full_wma.reset();
+        self.sqrt_wma.reset();
+    }
+}
+impl WarmupPeriod for Hma {
+    fn warmup_period(&self) -> usize {
+        self.half_wma
+            .warmup_period()
+            .max(self.full_wma.warmup_period())
+            + self.sqrt_wma.warmup_period()
+    }
+}
+impl Lookback for Hma {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        100.0,
+        100.62222222,
+        102.42222222,
+        102.9,
+        103.43333333,
+        106.12222222,
+    ];
+
+    test_indicator! {
+        new: Hma::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [Hma::new(0), Hma::new(1)],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{
+    Close, Current, High, Indicator, Lookback, Low, Max, Min, Next, Reset, Result, Warmup,
+    WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Ichimoku Kinko Hyo
+///
+/// The senkou spans computed on a bar belong to the bar `displacement` bars ahead,
+/// and the chikou span computed on a bar belongs to the bar `displacement` bars behind.
+/// See [`IchimokuOutput`] for which bar each value belongs to.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ichimoku {
+    tenkan_max: Max,
+    tenkan_min: Min,
+    kijun_max: Max,
+    kijun_min: Min,
+    senkou_b_max: Max,
+    senkou_b_min: Min,
+    displacement: usize,
+    ring: VecDeque<IchimokuCloud>,
+    count: usize,
+    current: Option<IchimokuOutput>,
+}
+impl Ichimoku {
+    pub const DEFAULT_TENKAN_PERIOD: usize = 9;
+    pub const DEFAULT_KIJUN_PERIOD: usize = 26;
+    pub const DEFAULT_SENKOU_B_PERIOD: usize = 52;
+    pub const DEFAULT_DISPLACEMENT: usize = 26;
+
+    pub fn new(
+        tenkan_period: usize,
+        kijun_period: usize,
+        senkou_b_period: usize,
+        displacement: usize,
+    ) -> Result<Self> {
+        Ok(Self {
+            tenkan_max: Max::new(tenkan_period)?,
+            tenkan_min: Min::new(tenkan_period)?,
+            kijun_max: Max::new(kijun_period)?,
+            kijun_min: Min::new(kijun_period)?,
+            senkou_b_max: Max::new(senkou_b_period)?,
+            senkou_b_min: Min::new(senkou_b_period)?,
+            displacement,
+            ring: VecDeque::with_capacity(displacement + 1),
+            count: 0,
+            current: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        tenkan_period: usize,
+        kijun_period: usize,
+        senkou_b_period: usize,
+        displacement: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::new(tenkan_period, kijun_period, senkou_b_period, displacement).map(W::apply)
+    }
+
+    /// Number of bars the senkou spans are shifted ahead and the chikou span is shifted behind
+    pub fn displacement(&self) -> usize {
+        self.displacement
+    }
+
+    fn _next(&mut self, high: f64, low: f64, close: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let tenkan = (self.tenkan_max.next(high) + self.tenkan_min.next(low)) / 2.0;
+        let kijun = (self.kijun_max.next(high) + self.kijun_min.next(low)) / 2.0;
+        let senkou_b = (self.senkou_b_max.next(high) + self.senkou_b_min.next(low)) / 2.0;
+        let leading_cloud = IchimokuCloud {
+            senkou_a: (tenkan + kijun) / 2.0,
+            senkou_b,
+        };
+
+        if self.ring.is_empty() {
+            for _ in 0..=self.displacement {
+                self.ring.push_back(leading_cloud);
+            }
+        } else {
+            let _ = self.ring.pop_front();
+            self.ring.push_back(leading_cloud);
+        }
+
+        self.current = IchimokuOutput {
+            tenkan,
+            kijun,
+            cloud: *self.ring.front().unwrap(),
+            leading_cloud,
+            chikou: close,
+        }
+        .into();
+        self.current().unwrap()
+    }
+}
+impl Default for Ichimoku {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_TENKAN_PERIOD,
+            Self::DEFAULT_KIJUN_PERIOD,
+            Self::DEFAULT_SENKOU_B_PERIOD,
+            Self::DEFAULT_DISPLACEMENT,
+        )
+        .unwrap()
+    }
+}
+
+impl Indicator for Ichimoku {
+    type Output = IchimokuOutput;
+}
+impl Current for Ichimoku {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<(f64, f64, f64)> for Ichimoku {
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close)
+    }
+}
+impl<Input: High + Low + Close> Next<&Input> for Ichimoku {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close())
+    }
+}
+impl Reset for Ichimoku {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.tenkan_max.reset();
+        self.tenkan_min.reset();
+        self.kijun_max.reset();
+        self.kijun_min.reset();
+        self.senkou_b_max.reset();
+        self.senkou_b_min.reset();
+        self.ring.clear();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Ichimoku {
+    fn warmup_period(&self) -> usize {
+        // The cloud of the current bar was computed `displacement` bars ago
+        let periods = [
+            self.tenkan_max.warmup_period(),
+            self.kijun_max.warmup_period(),
+            self.senkou_b_max.warmup_period(),
+        ];
+        periods.into_iter().max().unwrap() + self.displacement
+    }
+}
+impl Lookback for Ichimoku {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+/// Senkou span A and B
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IchimokuCloud {
+    pub senkou_a: f64,
+    pub senkou_b: f64,
+}
+impl From<(f64, f64)> for IchimokuCloud {
+    fn from((senkou_a, senkou_b): (f64, f64)) -> Self {
+        Self { senkou_a, senkou_b }
+    }
+}
+impl From<IchimokuCloud> for (f64, f64) {
+    fn from(x: IchimokuCloud) -> Self {
+        (x.senkou_a, x.senkou_b)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IchimokuOutput {
+    /// Tenkan-sen (conversion line) of the current bar
+    pub tenkan: f64,
+    /// Kijun-sen (base line) of the current bar
+    pub kijun: f64,
+    /// Cloud of the current bar, which was computed `displacement` bars ago
+    pub cloud: IchimokuCloud,
+    /// Cloud computed on the current bar, which belongs to the bar `displacement` bars ahead
+    pub leading_cloud: IchimokuCloud,
+    /// Chikou span (the close of the current bar), which belongs to the bar `displacement` bars behind
+    pub chikou: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for IchimokuCloud {
+        fn round(self) -> Self {
+            Self {
+                senkou_a: Round::round(self.senkou_a),
+                senkou_b: Round::round(self.senkou_b),
+            }
+        }
+    }
+    impl Round for IchimokuOutput {
+        fn round(self) -> Self {
+            Self {
+                tenkan: Round::round(self.tenkan),
+                kijun: Round::round(self.kijun),
+                cloud: Round::round(self.cloud),
+                leading_cloud: Round::round(self.leading_cloud),
+                chikou: Round::round(self.chikou),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+
+    const TENKAN_PERIOD: usize = 2;
+    const KIJUN_PERIOD: usize = 3;
+    const SENKOU_B_PERIOD: usize = 4;
+    const DISPLACEMENT: usize = 2;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.0),
+            (12.0, 10.0, 11.0),
+            (11.5, 9.5, 10.0),
+            (10.0, 8.5, 9.0),
+            (12.0, 9.0, 11.5),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[IchimokuOutput]>> = SyncLazy::new(|| {
+        [
+            ((9.0, 9.0), (9.0, 9.0), (9.0, 9.0), 9.0),
+            ((9.5, 9.5), (9.0, 9.0), (9.5, 9.5), 10.0),
+            ((10.5, 10.0), (9.0, 9.0), (10.25, 10.0), 11.0),
+            ((10.75, 10.5), (9.5, 9.5), (10.625, 10.0), 10.0),
+            ((10.0, 10.25), (10.25, 10.0), (10.125, 10.25), 9.0),
+            ((10.25, 10.25), (10.625, 10.0), (10.25, 10.25), 11.5),
+        ]
+        .into_iter()
+        .map(
+            |((tenkan, kijun), cloud, leading_cloud, chikou)| IchimokuOutput {
+                tenkan,
+                kijun,
+                cloud: cloud.into(),
+                leading_cloud: leading_cloud.into(),
+                chikou,
+            },
+        )
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: Ichimoku::new(TENKAN_PERIOD, KIJUN_PERIOD, SENKOU_B_PERIOD, DISPLACEMENT),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    Ichimoku::new(0, 1, 1, 1),
+                    Ichimoku::new(1, 0, 1, 1),
+                    Ichimoku::new(1, 1, 0, 1),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Ichimoku = Default::default();
+    }
+
+    #[test]
+    fn displacement() -> crate::Result<()> {
+        let mut ichimoku =
+            Ichimoku::new(TENKAN_PERIOD, KIJUN_PERIOD, SENKOU_B_PERIOD, DISPLACEMENT)?;
+
+        let outputs: Vec<_> = RANDOM_DATA.iter().map(|x| ichimoku.next(x)).collect();
+
+        for i in DISPLACEMENT..outputs.len() {
+            assert_eq!(outputs[i].cloud, outputs[i - DISPLACEMENT].leading_cloud);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{
+    Current, Indicator, InvalidBinaryRelationError, InvalidRangeError, Lookback, Next, Parameter,
+    Price, Range, Reset, Result, Warmup, WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Kaufman's Adaptive Moving Average
+///
+/// The smoothing constant moves between those of the fast and slow EMA according to the efficiency ratio.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Kama {
+    period: usize,
+    fast_alpha: f64,
+    slow_alpha: f64,
+    ring: VecDeque<f64>,
+    volatility: f64,
+    count: usize,
+    current: Option<KamaOutput>,
+}
+impl Kama {
+    pub const DEFAULT_PERIOD: usize = 10;
+    pub const DEFAULT_FAST_PERIOD: usize = 2;
+    pub const DEFAULT_SLOW_PERIOD: usize = 30;
+
+    pub fn new(period: usize, fast_period: usize, slow_period: usize) -> Result<Self> {
+        for (name, value) in [
+            ("period", period),
+            ("fast_period", fast_period),
+            ("slow_period", slow_period),
+        ] {
+            if value < 1 {
+                return Err(InvalidRangeError {
+                    param: Parameter::new(name, value),
+                    range: Range::LowerBounded { min: 1 },
+                }
+                .into());
+            }
+        }
+        if fast_period >= slow_period {
+            return Err(InvalidBinaryRelationError {
+                operator: "<",
+                lhs: Parameter::new("fast_period", fast_period),
+                rhs: Parameter::new("slow_period", slow_period),
+            }
+            .into());
+        }
+
+        Ok(Self {
+            period,
+            fast_alpha: 2.0 / (fast_period + 1) as f64,
+            slow_alpha: 2.0 / (slow_period + 1) as f64,
+            ring: VecDeque::with_capacity(period + 1),
+            volatility: 0.0,
+            count: 0,
+            current: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        period: usize,
+        fast_period: usize,
+        slow_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::new(period, fast_period, slow_period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        if self.ring.is_empty() {
+            for _ in 0..=self.period {
+                self.ring.push_back(input);
+            }
+        } else {
+            self.volatility += (input - self.ring.back().unwrap()).abs();
+            let old_input = self.ring.pop_front().unwrap();
+            self.volatility -= (self.ring.front().unwrap() - old_input).abs();
+            self.ring.push_back(input);
+        }
+
+        let direction = (input - self.ring.front().unwrap()).abs();
+        let efficiency_ratio = if self.volatility <= 0.0 {
+            0.0
+        } else {
+            direction / self.volatility
+        };
+        let alpha = efficiency_ratio * (self.fast_alpha - self.slow_alpha) + self.slow_alpha;
+        let alpha = alpha * alpha;
+
+        match &mut self.current {
+            Some(current) => {
+                current.kama += (input - current.kama) * alpha;
+                current.alpha = alpha;
+            }
+            None => {
+                self.current = KamaOutput { kama: input, alpha }.into();
+            }
+        }
+        self.current().unwrap()
+    }
+}
+impl Default for Kama {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_PERIOD,
+            Self::DEFAULT_FAST_PERIOD,
+            Self::DEFAULT_SLOW_PERIOD,
+        )
+        .unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KamaOutput {
+    pub kama: f64,
+    /// Smoothing constant derived from the efficiency ratio
+    pub alpha: f64,
+}
+impl From<(f64, f64)> for KamaOutput {
+    fn from((kama, alpha): (f64, f64)) -> Self {
+        Self { kama, alpha }
+    }
+}
+impl From<KamaOutput> for (f64, f64) {
+    fn from(x: KamaOutput) -> Self {
+        (x.kama, x.alpha)
+    }
+}
+
+impl Indicator for Kama {
+    type Output = KamaOutput;
+}
+impl Current for Kama {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for Kama {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Kama {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Kama {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ring.clear();
+        self.volatility = 0.0;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Kama {
+    fn warmup_period(&self) -> usize {
+        self.period
+    }
+}
+impl Lookback for Kama {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for KamaOutput {
+        fn round(self) -> Self {
+            Self {
+                kama: Round::round(self.kama),
+                alpha: Round::round(self.alpha),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    const FAST_PERIOD: usize = 2;
+    const SLOW_PERIOD: usize = 5;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0, 106.0, 108.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[KamaOutput]>> = SyncLazy::new(|| {
+        [
+            (100.00000000, 0.11111111),
+            (100.44444444, 0.44444444),
+            (101.58024691, 0.44444444),
+            (101.68518519, 0.25000000),
+            (102.34362140, 0.28444444),
+            (103.78077529, 0.30864198),
+            (104.46572119, 0.30864198),
+            (105.55654798, 0.30864198),
+        ]
+        .into_iter()
+        .map(KamaOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: Kama::new(PERIOD, FAST_PERIOD, SLOW_PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    Kama::new(0, 2, 5),
+                    Kama::new(3, 0, 5),
+                    Kama::new(3, 5, 5),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Kama = Default::default();
+    }
+}
@@ -0,0 +1,265 @@
+use crate::indicators::bolinger_bands::{bandwidth, percent_b};
+use crate::{
+    Atr, Close, Current, Ema, High, Indicator, InvalidRangeError, Lookback, Low, Next, Parameter,
+    Range, Reset, Result, Seed, Warmup, WarmupPeriod,
+};
+
+/// Keltner Channels
+///
+/// EMA of the close ± multiplier × ATR
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeltnerChannels {
+    ema: Ema,
+    atr: Atr,
+    multiplier: f64,
+    close: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeltnerChannelsOutput {
+    pub average: f64,
+    pub upper_bound: f64,
+    pub lower_bound: f64,
+    /// Position of the close within the channels (0.0 at the lower bound, 1.0 at the upper bound)
+    pub percent_b: f64,
+    /// Width of the channels relative to the average
+    pub bandwidth: f64,
+}
+impl From<(f64, f64, f64, f64, f64)> for KeltnerChannelsOutput {
+    fn from(
+        (average, upper_bound, lower_bound, percent_b, bandwidth): (f64, f64, f64, f64, f64),
+    ) -> Self {
+        Self {
+            average,
+            upper_bound,
+            lower_bound,
+            percent_b,
+            bandwidth,
+        }
+    }
+}
+impl From<KeltnerChannelsOutput> for (f64, f64, f64, f64, f64) {
+    fn from(x: KeltnerChannelsOutput) -> Self {
+        (
+            x.average,
+            x.upper_bound,
+            x.lower_bound,
+            x.percent_b,
+            x.bandwidth,
+        )
+    }
+}
+
+impl KeltnerChannels {
+    pub const DEFAULT_PERIOD: usize = 20;
+    pub const DEFAULT_ATR_PERIOD: usize = 10;
+    pub const DEFAULT_MULTIPLIER: f64 = 2.0;
+
+    pub fn new(period: usize, atr_period: usize, multiplier: f64) -> Result<Self> {
+        Self::with_seed(period, atr_period, multiplier, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        period: usize,
+        atr_period: usize,
+        multiplier: f64,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, atr_period, multiplier, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(
+        period: usize,
+        atr_period: usize,
+        multiplier: f64,
+        seed: Seed,
+    ) -> Result<Self> {
+        let ema = Ema::with_seed(period, seed)?;
+        let atr = Atr::with_seed(atr_period, seed)?;
+        if multiplier < 0.0 {
+            Err(InvalidRangeError {
+                param: Parameter::new("multiplier", multiplier),
+                range: Range::LowerBounded { min: 0.0 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                ema,
+                atr,
+                multiplier,
+                close: None,
+            })
+        }
+    }
+
+    fn _next(&mut self, high: f64, low: f64, close: f64) -> <Self as Indicator>::Output {
+        let _ = self.ema.next(close);
+        let _ = self.atr.next((high, low, close));
+        self.close = close.into();
+        self.current().unwrap()
+    }
+}
+impl Default for KeltnerChannels {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_PERIOD,
+            Self::DEFAULT_ATR_PERIOD,
+            Self::DEFAULT_MULTIPLIER,
+        )
+        .unwrap()
+    }
+}
+
+impl Indicator for KeltnerChannels {
+    type Output = KeltnerChannelsOutput;
+}
+impl Current for KeltnerChannels {
+    fn current(&self) -> Option<Self::Output> {
+        match (self.ema.current(), self.atr.current(), self.close) {
+            (Some(average), Some(atr), Some(close)) => {
+                let upper_bound = average + atr * self.multiplier;
+                let lower_bound = average - atr * self.multiplier;
+                Self::Output {
+                    average,
+                    upper_bound,
+                    lower_bound,
+                    percent_b: percent_b(close, upper_bound, lower_bound),
+                    bandwidth: bandwidth(average, upper_bound, lower_bound),
+                }
+                .into()
+            }
+            _ => None,
+        }
+    }
+}
+impl Next<(f64, f64, f64)> for KeltnerChannels {
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close)
+    }
+}
+impl<Input: High + Low + Close> Next<&Input> for KeltnerChannels {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close())
+    }
+}
+impl Reset for KeltnerChannels {
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.atr.reset();
+        self.close = None;
+    }
+}
+impl WarmupPeriod for KeltnerChannels {
+    fn warmup_period(&self) -> usize {
+        self.ema.warmup_period().max(self.atr.warmup_period())
+    }
+}
+impl Lookback for KeltnerChannels {
+    fn is_ready(&self) -> bool {
+        self.ema.is_ready() && self.atr.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for KeltnerChannelsOutput {
+        fn round(self) -> Self {
+            Self {
+                average: Round::round(self.average),
+                upper_bound: Round::round(self.upper_bound),
+                lower_bound: Round::round(self.lower_bound),
+                percent_b: Round::round(self.percent_b),
+                bandwidth: Round::round(self.bandwidth),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+
+    const PERIOD: usize = 3;
+    const ATR_PERIOD: usize = 3;
+    const MULTIPLIER: f64 = 2.0;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.0),
+            (12.0, 10.0, 11.0),
+            (15.0, 13.0, 14.0),
+            (13.0, 12.0, 12.5),
+            (12.0, 10.0, 11.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[KeltnerChannelsOutput]>> = SyncLazy::new(|| {
+        [
+            (9.0, 13.0, 5.0, 0.5, 0.88888889),
+            (9.5, 13.5, 5.5, 0.5625, 0.84210526),
+            (10.25, 14.25, 6.25, 0.59375, 0.7804878),
+            (12.125, 17.45833333, 6.79166667, 0.67578125, 0.87972509),
+            (12.3125, 17.20138889, 7.42361111, 0.51917614, 0.79413424),
+            (11.65625, 16.58217593, 6.73032407, 0.43338816, 0.84519909),
+        ]
+        .into_iter()
+        .map(KeltnerChannelsOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: KeltnerChannels::new(PERIOD, ATR_PERIOD, MULTIPLIER),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    KeltnerChannels::new(0, 2, 1.0),
+                    KeltnerChannels::new(2, 1, 1.0),
+                    KeltnerChannels::new(2, 2, -1.0),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: KeltnerChannels = Default::default();
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    Current, Indicator, LinearRegression, Lookback, Next, Price, Reset, Result, Warmup,
+    WarmupPeriod,
+};
+
+/// Linear Regression Angle
+///
+/// Angle of the [`LinearRegression`] slope in radians, which ranges in (-π/2, π/2).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinRegAngle {
+    linear_regression: LinearRegression,
+}
+impl LinRegAngle {
+    pub const DEFAULT_PERIOD: usize = LinearRegression::DEFAULT_PERIOD;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let linear_regression = LinearRegression::new(period)?;
+        Ok(Self { linear_regression })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.linear_regression.next(input).slope.atan()
+    }
+}
+impl Default for LinRegAngle {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for LinRegAngle {
+    type Output = f64;
+}
+impl Current for LinRegAngle {
+    fn current(&self) -> Option<Self::Output> {
+        self.linear_regression.current().map(|x| x.slope.atan())
+    }
+}
+impl Next<f64> for LinRegAngle {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for LinRegAngle {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for LinRegAngle {
+    fn reset(&mut self) {
+        self.linear_regression.reset();
+    }
+}
+impl WarmupPeriod for LinRegAngle {
+    fn warmup_period(&self) -> usize {
+        self.linear_regression.warmup_period()
+    }
+}
+impl Lookback for LinRegAngle {
+    fn is_ready(&self) -> bool {
+        self.linear_regression.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        0.0, 0.5404195, 0.46364761, 0.83298127, 0.5404195, 0.95054684, 0.5404195,
+    ];
+
+    test_indicator! {
+        new: LinRegAngle::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: LinRegAngle::new(2),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: LinRegAngle = Default::default();
+    }
+}
@@ -0,0 +1,380 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    Current, Indicator, InvalidRangeError, Lookback, Next, Parameter, Price, Range, Reset, Result,
+    Warmup, WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Linear Regression
+///
+/// Least squares line fitted to the last `period` prices, where the oldest bar is at x = 0
+/// and the current bar is at x = `period - 1`.
+/// Updated in O(1) per bar from the running mean and centered sums of squares and products,
+/// which are recomputed from the ring every `period` bars so rounding errors cannot pile up.
+/// A window of identical inputs yields a slope of exactly 0.0 and that input as the endpoint.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRegression {
+    period: usize,
+    ring: VecDeque<f64>,
+    /// (mean of y, sum of squared deviations of y, sum of products of deviations of x and y)
+    mean_syy_sxy: Option<(f64, f64, f64)>,
+    since_recompute: usize,
+    repeats: usize,
+    count: usize,
+}
+impl LinearRegression {
+    pub const DEFAULT_PERIOD: usize = 14;
+
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 3 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 3 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                period,
+                ring: VecDeque::with_capacity(period),
+                mean_syy_sxy: None,
+                since_recompute: 0,
+                repeats: 0,
+                count: 0,
+            })
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        match &mut self.mean_syy_sxy {
+            Some((mean, syy, sxy)) => {
+                let prev_input = *self.ring.back().unwrap();
+                let old_input = self.ring.pop_front().unwrap();
+                self.ring.push_back(input);
+
+                self.repeats = if input == prev_input {
+                    self.repeats + 1
+                } else {
+                    1
+                };
+                self.since_recompute += 1;
+
+                if self.period <= self.repeats {
+                    *mean = input;
+                    *syy = 0.0;
+                    *sxy = 0.0;
+                } else if self.period <= self.since_recompute {
+                    self.since_recompute = 0;
+                    self.mean_syy_sxy = Self::recompute(&self.ring).into();
+                } else {
+                    // Every remaining bar moves one step to the left on the x axis
+                    let x_mean = (self.period - 1) as f64 / 2.0;
+                    *sxy += (x_mean + 1.0) * (old_input - *mean) + x_mean * (input - *mean);
+
+                    let delta = input - old_input;
+                    let old_mean = *mean;
+                    *mean += delta / self.period as f64;
+                    let delta2 = input - *mean + old_input - old_mean;
+                    *syy += delta * delta2;
+                }
+            }
+            None => {
+                for _ in 0..self.period {
+                    self.ring.push_back(input);
+                }
+                self.mean_syy_sxy = (input, 0.0, 0.0).into();
+                self.repeats = self.period;
+            }
+        }
+        self.current().unwrap()
+    }
+
+    /// Two-pass mean and centered sums, taken relative to the oldest input
+    fn recompute(ring: &VecDeque<f64>) -> (f64, f64, f64) {
+        let shift = *ring.front().unwrap();
+        let mean = shift + ring.iter().map(|y| y - shift).sum::<f64>() / ring.len() as f64;
+        let x_mean = (ring.len() - 1) as f64 / 2.0;
+        ring.iter()
+            .enumerate()
+            .fold((mean, 0.0, 0.0), |(mean, syy, sxy), (x, y)| {
+                let dy = y - mean;
+                (mean, syy + dy * dy, sxy + (x as f64 - x_mean) * dy)
+            })
+    }
+}
+impl Default for LinearRegression {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for LinearRegression {
+    type Output = LinearRegressionOutput;
+}
+impl Current for LinearRegression {
+    fn current(&self) -> Option<Self::Output> {
+        let (mean, syy, sxy) = self.mean_syy_sxy?;
+        let n = self.period as f64;
+        let x_mean = (n - 1.0) / 2.0;
+        let sxx = n * (n * n - 1.0) / 12.0;
+
+        let slope = sxy / sxx;
+        let intercept = mean - slope * x_mean;
+        let r_squared = if syy <= 0.0 {
+            0.0
+        } else {
+            (sxy * sxy / (sxx * syy)).min(1.0)
+        };
+        let sse = (syy - sxy * sxy / sxx).max(0.0);
+
+        LinearRegressionOutput {
+            slope,
+            intercept,
+            r_squared,
+            standard_error: (sse / (n - 2.0)).sqrt(),
+            endpoint: mean + slope * x_mean,
+        }
+        .into()
+    }
+}
+impl Next<f64> for LinearRegression {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for LinearRegression {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for LinearRegression {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ring.clear();
+        self.mean_syy_sxy = None;
+        self.since_recompute = 0;
+        self.repeats = 0;
+    }
+}
+impl WarmupPeriod for LinearRegression {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl Lookback for LinearRegression {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRegressionOutput {
+    pub slope: f64,
+    /// Value of the regression line at the oldest bar of the window
+    pub intercept: f64,
+    /// Coefficient of determination, or 0.0 while the window is flat
+    pub r_squared: f64,
+    /// Standard error of the estimate, with `period - 2` degrees of freedom
+    pub standard_error: f64,
+    /// Value of the regression line at the current bar (a.k.a. LSMA)
+    pub endpoint: f64,
+}
+impl From<(f64, f64, f64, f64, f64)> for LinearRegressionOutput {
+    fn from(
+        (slope, intercept, r_squared, standard_error, endpoint): (f64, f64, f64, f64, f64),
+    ) -> Self {
+        Self {
+            slope,
+            intercept,
+            r_squared,
+            standard_error,
+            endpoint,
+        }
+    }
+}
+impl From<LinearRegressionOutput> for (f64, f64, f64, f64, f64) {
+    fn from(x: LinearRegressionOutput) -> Self {
+        (
+            x.slope,
+            x.intercept,
+            x.r_squared,
+            x.standard_error,
+            x.endpoint,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl Round for LinearRegressionOutput {
+        fn round(self) -> Self {
+            Self {
+                slope: Round::round(self.slope),
+                intercept: Round::round(self.intercept),
+                r_squared: Round::round(self.r_squared),
+                standard_error: Round::round(self.standard_error),
+                endpoint: Round::round(self.endpoint),
+            }
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[LinearRegressionOutput]>> = SyncLazy::new(|| {
+        [
+            (0.0, 100.0, 0.0, 0.0, 100.0),
+            (0.6, 99.6, 0.6, 0.77459667, 101.4),
+            (0.5, 100.0, 0.45454545, 0.8660254, 101.5),
+            (1.1, 100.1, 0.69142857, 1.161895, 103.4),
+            (0.6, 101.6, 0.36, 1.26491106, 103.4),
+            (1.4, 101.4, 0.75384615, 1.26491106, 105.6),
+            (0.6, 103.6, 0.36, 1.26491106, 105.4),
+        ]
+        .into_iter()
+        .map(LinearRegressionOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: LinearRegression::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    LinearRegression::new(0),
+                    LinearRegression::new(2),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: LinearRegression = Default::default();
+    }
+
+    #[test]
+    fn compare_with_naive() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut linear_regression = LinearRegression::new(PERIOD)?;
+        let mut ring = VecDeque::new();
+
+        for x in RANDOM_DATA.iter() {
+            let y = x.price();
+            if ring.is_empty() {
+                ring.extend([y; PERIOD]);
+            } else {
+                ring.pop_front();
+                ring.push_back(y);
+            }
+
+            let n = PERIOD as f64;
+            let x_mean = (n - 1.0) / 2.0;
+            let y_mean = ring.iter().sum::<f64>() / n;
+            let (sxx, sxy) = ring
+                .iter()
+                .enumerate()
+                .fold((0.0, 0.0), |(sxx, sxy), (i, y)| {
+                    let dx = i as f64 - x_mean;
+                    (sxx + dx * dx, sxy + dx * (y - y_mean))
+                });
+            let slope = sxy / sxx;
+            let endpoint = y_mean + slope * x_mean;
+
+            let output = linear_regression.next(x);
+            assert!((output.slope - slope).abs() < PRECISION);
+            assert!((output.endpoint - endpoint).abs() < PRECISION * 1e2);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_run_drift() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        const UPDATES: usize = 5_000_000;
+        let mut linear_regression = LinearRegression::new(PERIOD)?;
+
+        let inputs: Vec<f64> = RANDOM_DATA
+            .iter()
+            .map(|x| 1e4 + x.price())
+            .cycle()
+            .take(UPDATES)
+            .collect();
+        for input in inputs.iter() {
+            let _ = linear_regression.next(*input);
+        }
+
+        let window: VecDeque<f64> = inputs[UPDATES - PERIOD..].iter().copied().collect();
+        let (mean, _, sxy) = LinearRegression::recompute(&window);
+        let n = PERIOD as f64;
+        let slope = sxy / (n * (n * n - 1.0) / 12.0);
+        let output = linear_regression.current().unwrap();
+        assert!((output.slope - slope).abs() < 1e-10);
+        assert!((output.endpoint - (mean + slope * (n - 1.0) / 2.0)).abs() < 1e-8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flat() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut linear_regression = LinearRegression::new(PERIOD)?;
+
+        for x in RANDOM_DATA.iter() {
+            let _ = linear_regression.next(x);
+        }
+        for _ in 0..PERIOD {
+            let _ = linear_regression.next(0.1);
+        }
+
+        let output = linear_regression.current().unwrap();
+        assert_eq!(output.slope, 0.0);
+        assert_eq!(output.endpoint, 0.1);
+        assert_eq!(output.r_squared, 0.0);
+        assert_eq!(output.standard_error, 0.0);
+
+        Ok(())
+    }
+}
@@ -1,12 +1,16 @@
 use crate::operators::Diff;
 use crate::{
-    Current, Ema, Indicator, InvalidBinaryRelationError, Next, Parameter, Price, Reset, Result, Sma,
+    Current, Ema, Indicator, InvalidBinaryRelationError, Lookback, Next, Parameter, Price, Reset,
+    Result, Seed, Sma, Warmup, WarmupPeriod,
 };
 
 /// Moving Average Convergence Divergence
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Macd {
     macd: Diff<Ema, Ema>,
     signal: Sma,
+    count: usize,
 }
 
 impl Macd {
@@ -15,26 +19,56 @@ impl Macd {
     pub const DEFAULT_SIGNAL_PERIOD: usize = 9;
 
     pub fn new(short_period: usize, long_period: usize, signal_period: usize) -> Result<Self> {
-        if !(short_period < long_period) {
-            return Err(InvalidBinaryRelationError {
-                operator: "<",
-                lhs: Parameter::new("short_period", short_period),
-                rhs: Parameter::new("long_period", long_period),
-            }
-            .into());
-        }
+        Self::with_seed(short_period, long_period, signal_period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        short_period: usize,
+        long_period: usize,
+        signal_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(short_period, long_period, signal_period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(
+        short_period: usize,
+        long_period: usize,
+        signal_period: usize,
+        seed: Seed,
+    ) -> Result<Self> {
+        check_short_long(short_period, long_period)?;
 
         Ok(Self {
-            macd: Diff::new(Ema::new(short_period)?, Ema::new(long_period)?),
+            macd: Diff::new(
+                Ema::with_seed(short_period, seed)?,
+                Ema::with_seed(long_period, seed)?,
+            ),
             signal: Sma::new(signal_period)?,
+            count: 0,
         })
     }
 
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         let _ = self.signal.next(self.macd.next((input, input)));
         self.current().unwrap()
     }
 }
+/// Fail unless the short period is shorter than the long one, as MACD and its relatives need
+pub(crate) fn check_short_long(short_period: usize, long_period: usize) -> Result<()> {
+    if short_period >= long_period {
+        Err(InvalidBinaryRelationError {
+            operator: "<",
+            lhs: Parameter::new("short_period", short_period),
+            rhs: Parameter::new("long_period", long_period),
+        }
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
 impl Default for Macd {
     fn default() -> Self {
         Self {
@@ -43,6 +77,7 @@ impl Default for Macd {
                 Ema::new(Self::DEFAULT_LONG_PERIOD).unwrap(),
             ),
             signal: Sma::new(Self::DEFAULT_SIGNAL_PERIOD).unwrap(),
+            count: 0,
         }
     }
 }
@@ -75,12 +110,25 @@ impl<Input: Price> Next<&Input> for Macd {
 }
 impl Reset for Macd {
     fn reset(&mut self) {
+        self.count = 0;
         self.macd.reset();
         self.signal.reset();
     }
 }
+impl WarmupPeriod for Macd {
+    fn warmup_period(&self) -> usize {
+        // The long EMA is the last to warm up
+        self.macd.rhs().warmup_period() + self.signal.warmup_period()
+    }
+}
+impl Lookback for Macd {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MacdOutput {
     pub macd: f64,
     pub signal: f64,
@@ -95,9 +143,9 @@ impl From<(f64, f64, f64)> for MacdOutput {
         }
     }
 }
-impl Into<(f64, f64, f64)> for MacdOutput {
-    fn into(self) -> (f64, f64, f64) {
-        (self.macd, self.signal, self.histogram)
+impl From<MacdOutput> for (f64, f64, f64) {
+    fn from(x: MacdOutput) -> Self {
+        (x.macd, x.signal, x.histogram)
     }
 }
 
@@ -172,6 +220,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
 
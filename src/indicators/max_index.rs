@@ -1,11 +1,18 @@
-use crate::{Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result};
+use crate::{
+    collections::{Extremum, MonotonicDeque},
+    Current, Indicator, InvalidRangeError, Lookback, Next, Parameter, Price, Range, Reset, Result,
+    Warmup, WarmupPeriod,
+};
 use alloc::collections::VecDeque;
 
 /// Maximum Index (Number of days elapsed from the date of the highest price)
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaxIndex {
     period: usize,
     ring: VecDeque<f64>,
+    deque: MonotonicDeque,
+    count: usize,
     current: Option<usize>,
 }
 impl MaxIndex {
@@ -20,26 +27,35 @@ impl MaxIndex {
             Ok(Self {
                 period,
                 ring: VecDeque::with_capacity(period),
+                deque: MonotonicDeque::new(period, Extremum::Max),
+                count: 0,
                 current: None,
             })
         }
     }
 
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         match &mut self.current {
             Some(max_index) => {
                 let max = self.ring[*max_index];
 
                 let old_value = self.ring.pop_back().unwrap();
                 self.ring.push_front(input);
+                self.deque.push(input);
 
                 match max {
                     max if max <= input => *max_index = 0,
                     max if max == old_value => {
-                        for (index, x) in self.ring.iter().enumerate() {
-                            if self.ring[*max_index] < *x {
-                                *max_index = index;
-                            }
+                        // As if rescanning from the bar after the previous maximum:
+                        // keep that bar if it ties with the new maximum, otherwise take its newest occurrence.
+                        let (newest_index, new_max) = self.deque.front().unwrap();
+                        if self.ring[*max_index] != new_max {
+                            *max_index = newest_index;
                         }
                     }
                     _ => *max_index += 1,
@@ -49,6 +65,7 @@ impl MaxIndex {
                 for _ in 0..self.period {
                     self.ring.push_back(input);
                 }
+                self.deque.push(input);
                 self.current = 0.into();
             }
         }
@@ -76,10 +93,22 @@ impl<Input: Price> Next<&Input> for MaxIndex {
 }
 impl Reset for MaxIndex {
     fn reset(&mut self) {
+        self.count = 0;
         self.ring.clear();
+        self.deque.clear();
         self.current = None;
     }
 }
+impl WarmupPeriod for MaxIndex {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl Lookback for MaxIndex {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -124,6 +153,67 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
+
+    /// The rescanning implementation that `MaxIndex` used to have
+    fn naive(period: usize, inputs: impl Iterator<Item = f64>) -> Vec<usize> {
+        let mut ring = VecDeque::with_capacity(period);
+        let mut current: Option<usize> = None;
+        inputs
+            .map(|input| {
+                match &mut current {
+                    Some(max_index) => {
+                        let max = ring[*max_index];
+
+                        let old_value = ring.pop_back().unwrap();
+                        ring.push_front(input);
+
+                        match max {
+                            max if max <= input => *max_index = 0,
+                            max if max == old_value => {
+                                for (index, x) in ring.iter().enumerate() {
+                                    if ring[*max_index] < *x {
+                                        *max_index = index;
+                                    }
+                                }
+                            }
+                            _ => *max_index += 1,
+                        }
+                    }
+                    None => {
+                        for _ in 0..period {
+                            ring.push_back(input);
+                        }
+                        current = 0.into();
+                    }
+                }
+                current.unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compare_with_naive() -> crate::Result<()> {
+        // Rounded prices produce plenty of ties
+        let inputs: [Vec<f64>; 2] = [
+            RANDOM_DATA.iter().map(|x| x.price()).collect(),
+            RANDOM_DATA.iter().map(|x| x.price().round()).collect(),
+        ];
+
+        for inputs in inputs.iter() {
+            for period in [1, 2, 3, 5, 20, 200] {
+                let mut indicator = MaxIndex::new(period)?;
+                let correct = naive(period, inputs.iter().copied());
+                for (input, correct) in inputs.iter().zip(correct) {
+                    assert_eq!(indicator.next(*input), correct);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
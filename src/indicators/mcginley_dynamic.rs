@@ -0,0 +1,267 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    Current, Indicator, InvalidRangeError, Lookback, Next, Parameter, Price, Range, Reset, Result,
+    Seed, Warmup, WarmupPeriod,
+};
+
+/// McGinley Dynamic
+///
+/// The smoothing constant `1 / (period * (input / value)^4)` speeds up in falling markets
+/// and slows down in rising ones.
+/// The constant is capped at 1, so that a sharp drop moves the value onto the input and no further.
+/// Where `period * (input / value)^4` is 0 or not finite, as when the input or the previous value is 0
+/// or the ratio underflows, it falls back to `1 / period`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct McGinleyDynamic {
+    period: usize,
+    seed: Seed,
+    count: usize,
+    current: Option<McGinleyDynamicOutput>,
+}
+impl McGinleyDynamic {
+    pub const DEFAULT_PERIOD: usize = 14;
+
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_seed(period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(period: usize, seed: Seed) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                period,
+                seed,
+                count: 0,
+                current: None,
+            })
+        }
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        let value = match self.current {
+            Some(current) => current.mcginley_dynamic,
+            None => input,
+        };
+        self.count = self.count.saturating_add(1);
+        let alpha = match self.seed {
+            Seed::Mean if self.count <= self.period => 1.0 / self.count as f64,
+            _ => {
+                let denominator = self.period as f64 * (input / value).powi(4);
+                if denominator > 0.0 && denominator.is_finite() {
+                    (1.0 / denominator).min(1.0)
+                } else {
+                    1.0 / self.period as f64
+                }
+            }
+        };
+        self.current = McGinleyDynamicOutput {
+            mcginley_dynamic: value + (input - value) * alpha,
+            alpha,
+        }
+        .into();
+        self.current().unwrap()
+    }
+}
+impl Default for McGinleyDynamic {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct McGinleyDynamicOutput {
+    pub mcginley_dynamic: f64,
+    /// Speed adjustment `1 / (period * (input / value)^4)`, capped at 1
+    pub alpha: f64,
+}
+impl From<(f64, f64)> for McGinleyDynamicOutput {
+    fn from((mcginley_dynamic, alpha): (f64, f64)) -> Self {
+        Self {
+            mcginley_dynamic,
+            alpha,
+        }
+    }
+}
+impl From<McGinleyDynamicOutput> for (f64, f64) {
+    fn from(x: McGinleyDynamicOutput) -> Self {
+        (x.mcginley_dynamic, x.alpha)
+    }
+}
+
+impl Indicator for McGinleyDynamic {
+    type Output = McGinleyDynamicOutput;
+}
+impl Current for McGinleyDynamic {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for McGinleyDynamic {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for McGinleyDynamic {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for McGinleyDynamic {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for McGinleyDynamic {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl Lookback for McGinleyDynamic {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for McGinleyDynamicOutput {
+        fn round(self) -> Self {
+            Self {
+                mcginley_dynamic: Round::round(self.mcginley_dynamic),
+                alpha: Round::round(self.alpha),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0, 106.0, 108.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[McGinleyDynamicOutput]>> = SyncLazy::new(|| {
+        [
+            (100.00000000, 0.33333333),
+            (100.32032678, 0.32032678),
+            (101.12416275, 0.29997537),
+            (101.40620958, 0.32203110),
+            (102.18772665, 0.30130309),
+            (103.52213538, 0.27729279),
+            (104.27352596, 0.30324118),
+            (105.35291514, 0.28965429),
+        ]
+        .into_iter()
+        .map(McGinleyDynamicOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: McGinleyDynamic::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: McGinleyDynamic::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: McGinleyDynamic = Default::default();
+    }
+
+    #[test]
+    fn zero_input() -> crate::Result<()> {
+        let mut mcginley_dynamic = McGinleyDynamic::new(PERIOD)?;
+
+        let _ = mcginley_dynamic.next(100.0);
+        let output = mcginley_dynamic.next(0.0);
+        assert_eq!(output.alpha, 1.0 / PERIOD as f64);
+        // A previous value of exactly 0 falls back as well
+        let mut from_zero = McGinleyDynamic::new(PERIOD)?;
+        let _ = from_zero.next(0.0);
+        assert_eq!(from_zero.next(100.0).alpha, 1.0 / PERIOD as f64);
+
+        for x in [0.0, 100.0, 101.0, 0.0, 99.0] {
+            let output = mcginley_dynamic.next(x);
+            assert!(output.mcginley_dynamic.is_finite() && output.alpha.is_finite());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn tiny_input() -> crate::Result<()> {
+        let mut mcginley_dynamic = McGinleyDynamic::new(PERIOD)?;
+
+        let _ = mcginley_dynamic.next(100.0);
+        // (1e-90 / 100)^4 underflows to 0
+        let output = mcginley_dynamic.next(1e-90);
+        assert_eq!(output.alpha, 1.0 / PERIOD as f64);
+        for x in [100.0, 1e-90, 101.0] {
+            let output = mcginley_dynamic.next(x);
+            assert!(output.mcginley_dynamic.is_finite() && output.alpha.is_finite());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sharp_drop() -> crate::Result<()> {
+        let mut mcginley_dynamic = McGinleyDynamic::new(14)?;
+
+        let _ = mcginley_dynamic.next(100.0);
+        // 1 / (14 * 0.2^4) would be 44.6
+        let output = mcginley_dynamic.next(20.0);
+        assert_eq!(output.alpha, 1.0);
+        assert_eq!(output.mcginley_dynamic, 20.0);
+        let output = mcginley_dynamic.next(20.0);
+        assert!(0.0 < output.alpha && output.alpha <= 1.0);
+        assert_eq!(output.mcginley_dynamic, 20.0);
+
+        Ok(())
+    }
+}
@@ -1,15 +1,47 @@
-use crate::{Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result};
-use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use crate::Warmup;
+use crate::{
+    collections::{Extremum, MonotonicDeque},
+    storage::check_capacity,
+    Current, DefaultStorage, Indicator, Inline, InvalidRangeError, Lookback, Next, Number,
+    Parameter, Price, Range, Reset, Result, Storage, WarmupPeriod,
+};
 
 /// Minimum
+///
+/// Tracked with a monotonic deque like [`Max`](crate::Max).
 #[derive(Debug, Clone)]
-pub struct Min {
-    period: usize,
-    ring: VecDeque<f64>,
-    current: Option<f64>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, S::Ring<(usize, T)>: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, S::Ring<(usize, T)>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Min<T: Number = f64, S: Storage = DefaultStorage> {
+    deque: MonotonicDeque<T, S>,
+    count: usize,
+    current: Option<T>,
 }
+
+/// [`Min`] over a period of `P` fixed at compile time, kept inline without allocating
+///
+/// Built by `MinN::<P>::default()`, and outputs exactly what `Min::new(P)` does.
+pub type MinN<const P: usize, T = f64> = Min<T, Inline<P>>;
+#[cfg(feature = "alloc")]
 impl Min {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+}
+impl<T: Number, S: Storage> Min<T, S> {
+    /// Same as `new`, in any [`Number`] type and [`Storage`]
+    pub fn with_period(period: usize) -> Result<Self> {
         if period < 1 {
             Err(InvalidRangeError {
                 param: Parameter::new("period", period),
@@ -17,68 +49,61 @@ impl Min {
             }
             .into())
         } else {
+            check_capacity::<S>(period, period)?;
             Ok(Self {
-                period,
-                ring: VecDeque::with_capacity(period),
+                deque: MonotonicDeque::new(period, Extremum::Min),
+                count: 0,
                 current: None,
             })
         }
     }
 
-    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
-        match &mut self.current {
-            Some(min) => {
-                let old_val = self.ring.pop_front().unwrap();
-                self.ring.push_back(input);
-
-                match min {
-                    min if input <= *min => *min = input,
-                    min if *min == old_val => {
-                        *min = self
-                            .ring
-                            .iter()
-                            .copied()
-                            .reduce(|acc, x| acc.min(x))
-                            .unwrap();
-                    }
-                    _ => (),
-                }
-            }
-            None => {
-                for _ in 0..self.period {
-                    self.ring.push_back(input);
-                }
-                self.current = input.into();
-            }
-        }
-        self.current().unwrap()
+    fn _next(&mut self, input: T) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        self.deque.push(input);
+        let (_, min) = self.deque.front().unwrap();
+        self.current = min.into();
+        min
+    }
+}
+impl<T: Number, const P: usize> Default for Min<T, Inline<P>> {
+    fn default() -> Self {
+        const { assert!(0 < P, "period must be at least 1") };
+        Self::with_period(P).unwrap()
     }
 }
 
-impl Indicator for Min {
-    type Output = f64;
+impl<T: Number, S: Storage> Indicator for Min<T, S> {
+    type Output = T;
 }
-impl Current for Min {
+impl<T: Number, S: Storage> Current for Min<T, S> {
     fn current(&self) -> Option<Self::Output> {
         self.current
     }
 }
-impl Next<f64> for Min {
-    fn next(&mut self, input: f64) -> Self::Output {
-        self._next(input)
-    }
-}
-impl<Input: Price> Next<&Input> for Min {
+impl_next_for_numbers!(storage Min);
+impl<T: Number, S: Storage, Input: Price<T>> Next<&Input> for Min<T, S> {
     fn next(&mut self, input: &Input) -> Self::Output {
         self._next(input.price())
     }
 }
-impl Reset for Min {
+impl<T: Number, S: Storage> Reset for Min<T, S> {
     fn reset(&mut self) {
-        self.ring.clear();
+        self.count = 0;
+        self.deque.clear();
         self.current = None;
     }
 }
+impl<T: Number, S: Storage> WarmupPeriod for Min<T, S> {
+    fn warmup_period(&self) -> usize {
+        self.deque.period() - 1
+    }
+}
+impl<T: Number, S: Storage> Lookback for Min<T, S> {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -86,6 +111,7 @@ mod tests {
 
     use super::*;
     use crate::test_helper::*;
+    use alloc::collections::VecDeque;
 
     #[derive(Clone)]
     struct TestItem(f64);
@@ -123,6 +149,74 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    /// The rescanning implementation that `Min` used to have
+    fn naive(period: usize, inputs: impl Iterator<Item = f64>) -> Vec<f64> {
+        let mut ring = VecDeque::with_capacity(period);
+        let mut current: Option<f64> = None;
+        inputs
+            .map(|input| {
+                match &mut current {
+                    Some(min) => {
+                        let old_val = ring.pop_front().unwrap();
+                        ring.push_back(input);
+
+                        match min {
+                            min if input <= *min => *min = input,
+                            min if *min == old_val => {
+                                *min = ring.iter().copied().reduce(|acc, x| acc.min(x)).unwrap();
+                            }
+                            _ => (),
+                        }
+                    }
+                    None => {
+                        for _ in 0..period {
+                            ring.push_back(input);
+                        }
+                        current = input.into();
+                    }
+                }
+                current.unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compare_with_naive() -> crate::Result<()> {
+        // Rounded prices produce plenty of ties
+        let inputs: [Vec<f64>; 2] = [
+            RANDOM_DATA.iter().map(|x| x.price()).collect(),
+            RANDOM_DATA.iter().map(|x| x.price().round()).collect(),
+        ];
+
+        for inputs in inputs.iter() {
+            for period in [1, 2, 3, 5, 20, 200] {
+                let mut indicator = Min::new(period)?;
+                let correct = naive(period, inputs.iter().copied());
+                for (input, correct) in inputs.iter().zip(correct) {
+                    assert_eq!(indicator.next(*input), correct);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn const_period() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut min = Min::new(PERIOD)?;
+        let mut inline = MinN::<PERIOD>::default();
+
+        for x in RANDOM_DATA.iter() {
+            assert_eq!(inline.next(x), min.next(x));
         }
+
+        Ok(())
     }
 }
@@ -1,11 +1,18 @@
-use crate::{Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result};
+use crate::{
+    collections::{Extremum, MonotonicDeque},
+    Current, Indicator, InvalidRangeError, Lookback, Next, Parameter, Price, Range, Reset, Result,
+    Warmup, WarmupPeriod,
+};
 use alloc::collections::VecDeque;
 
 /// Minimum Index (Number of days elapsed from the date of the lowest price)
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MinIndex {
     period: usize,
     ring: VecDeque<f64>,
+    deque: MonotonicDeque,
+    count: usize,
     current: Option<usize>,
 }
 impl MinIndex {
@@ -20,26 +27,35 @@ impl MinIndex {
             Ok(Self {
                 period,
                 ring: VecDeque::with_capacity(period),
+                deque: MonotonicDeque::new(period, Extremum::Min),
+                count: 0,
                 current: None,
             })
         }
     }
 
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         match &mut self.current {
             Some(min_index) => {
                 let min = self.ring[*min_index];
 
                 let old_value = self.ring.pop_back().unwrap();
                 self.ring.push_front(input);
+                self.deque.push(input);
 
                 match min {
                     min if input <= min => *min_index = 0,
                     min if min == old_value => {
-                        for (index, x) in self.ring.iter().enumerate() {
-                            if *x < self.ring[*min_index] {
-                                *min_index = index;
-                            }
+                        // As if rescanning from the bar after the previous minimum:
+                        // keep that bar if it ties with the new minimum, otherwise take its newest occurrence.
+                        let (newest_index, new_min) = self.deque.front().unwrap();
+                        if self.ring[*min_index] != new_min {
+                            *min_index = newest_index;
                         }
                     }
                     _ => *min_index += 1,
@@ -49,6 +65,7 @@ impl MinIndex {
                 for _ in 0..self.period {
                     self.ring.push_back(input);
                 }
+                self.deque.push(input);
                 self.current = 0.into();
             }
         }
@@ -77,10 +94,22 @@ impl<Input: Price> Next<&Input> for MinIndex {
 
 impl Reset for MinIndex {
     fn reset(&mut self) {
+        self.count = 0;
         self.ring.clear();
+        self.deque.clear();
         self.current = None;
     }
 }
+impl WarmupPeriod for MinIndex {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl Lookback for MinIndex {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -125,6 +154,67 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
+
+    /// The rescanning implementation that `MinIndex` used to have
+    fn naive(period: usize, inputs: impl Iterator<Item = f64>) -> Vec<usize> {
+        let mut ring = VecDeque::with_capacity(period);
+        let mut current: Option<usize> = None;
+        inputs
+            .map(|input| {
+                match &mut current {
+                    Some(min_index) => {
+                        let min = ring[*min_index];
+
+                        let old_value = ring.pop_back().unwrap();
+                        ring.push_front(input);
+
+                        match min {
+                            min if input <= min => *min_index = 0,
+                            min if min == old_value => {
+                                for (index, x) in ring.iter().enumerate() {
+                                    if *x < ring[*min_index] {
+                                        *min_index = index;
+                                    }
+                                }
+                            }
+                            _ => *min_index += 1,
+                        }
+                    }
+                    None => {
+                        for _ in 0..period {
+                            ring.push_back(input);
+                        }
+                        current = 0.into();
+                    }
+                }
+                current.unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compare_with_naive() -> crate::Result<()> {
+        // Rounded prices produce plenty of ties
+        let inputs: [Vec<f64>; 2] = [
+            RANDOM_DATA.iter().map(|x| x.price()).collect(),
+            RANDOM_DATA.iter().map(|x| x.price().round()).collect(),
+        ];
+
+        for inputs in inputs.iter() {
+            for period in [1, 2, 3, 5, 20, 200] {
+                let mut indicator = MinIndex::new(period)?;
+                let correct = naive(period, inputs.iter().copied());
+                for (input, correct) in inputs.iter().zip(correct) {
+                    assert_eq!(indicator.next(*input), correct);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
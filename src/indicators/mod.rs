@@ -1,33 +1,197 @@
+pub mod accumulation_distribution;
+#[cfg(feature = "alloc")]
+pub mod apo;
+#[cfg(feature = "alloc")]
 pub mod aroon_indicator;
+#[cfg(feature = "alloc")]
 pub mod aroon_oscillator;
+pub mod atr;
+#[cfg(feature = "alloc")]
 pub mod bolinger_bands;
+#[cfg(feature = "alloc")]
+pub mod cci;
+#[cfg(feature = "alloc")]
+pub mod chaikin_money_flow;
+pub mod chaikin_oscillator;
+#[cfg(feature = "alloc")]
+pub mod connors_rsi;
+pub mod dema;
+#[cfg(feature = "alloc")]
+pub mod dmi;
+#[cfg(feature = "alloc")]
+pub mod donchian_channels;
 pub mod ema;
+#[cfg(feature = "alloc")]
+pub mod frama;
+#[cfg(feature = "alloc")]
+pub mod hma;
+#[cfg(feature = "alloc")]
+pub mod ichimoku;
+#[cfg(feature = "alloc")]
+pub mod kama;
+#[cfg(feature = "alloc")]
+pub mod keltner_channels;
+#[cfg(feature = "alloc")]
+pub mod lin_reg_angle;
+#[cfg(feature = "alloc")]
+pub mod linear_regression;
+#[cfg(feature = "alloc")]
 pub mod macd;
 pub mod max;
+#[cfg(feature = "alloc")]
 pub mod max_index;
+pub mod mcginley_dynamic;
 pub mod min;
+#[cfg(feature = "alloc")]
 pub mod min_index;
+pub mod momentum;
+#[cfg(feature = "alloc")]
+pub mod money_flow_index;
+pub mod natr;
+pub mod obv;
+pub mod parabolic_sar;
+#[cfg(feature = "alloc")]
+pub mod percent_rank;
+#[cfg(feature = "alloc")]
+pub mod ppo;
 pub mod rma;
+#[cfg(feature = "alloc")]
+pub mod roc;
+#[cfg(feature = "alloc")]
+pub mod rolling_beta;
+#[cfg(feature = "alloc")]
+pub mod rolling_correlation;
+#[cfg(feature = "alloc")]
+pub mod rolling_covariance;
+#[cfg(feature = "alloc")]
+pub mod rolling_kurtosis;
+#[cfg(feature = "alloc")]
+pub mod rolling_median;
+#[cfg(feature = "alloc")]
+pub mod rolling_quantile;
+#[cfg(feature = "alloc")]
+pub mod rolling_skewness;
+#[cfg(feature = "alloc")]
+pub mod rolling_z_score;
 pub mod rsi;
 pub mod sma;
 pub mod standard_deviation;
+#[cfg(feature = "alloc")]
+pub mod stoch_rsi;
+#[cfg(feature = "alloc")]
 pub mod stochastics;
+pub mod t3;
+pub mod tema;
+#[cfg(feature = "alloc")]
+pub mod time_series_forecast;
+#[cfg(feature = "alloc")]
+pub mod trima;
+pub mod trix;
+pub mod true_range;
+pub mod tsi;
+#[cfg(feature = "alloc")]
+pub mod vidya;
 pub mod vwap;
 pub mod vwma;
+#[cfg(feature = "alloc")]
+pub mod williams_r;
+pub mod wma;
 
+pub use accumulation_distribution::*;
+#[cfg(feature = "alloc")]
+pub use apo::*;
+#[cfg(feature = "alloc")]
 pub use aroon_indicator::*;
+#[cfg(feature = "alloc")]
 pub use aroon_oscillator::*;
+pub use atr::*;
+#[cfg(feature = "alloc")]
 pub use bolinger_bands::*;
+#[cfg(feature = "alloc")]
+pub use cci::*;
+#[cfg(feature = "alloc")]
+pub use chaikin_money_flow::*;
+pub use chaikin_oscillator::*;
+#[cfg(feature = "alloc")]
+pub use connors_rsi::*;
+pub use dema::*;
+#[cfg(feature = "alloc")]
+pub use dmi::*;
+#[cfg(feature = "alloc")]
+pub use donchian_channels::*;
 pub use ema::*;
+#[cfg(feature = "alloc")]
+pub use frama::*;
+#[cfg(feature = "alloc")]
+pub use hma::*;
+#[cfg(feature = "alloc")]
+pub use ichimoku::*;
+#[cfg(feature = "alloc")]
+pub use kama::*;
+#[cfg(feature = "alloc")]
+pub use keltner_channels::*;
+#[cfg(feature = "alloc")]
+pub use lin_reg_angle::*;
+#[cfg(feature = "alloc")]
+pub use linear_regression::*;
+#[cfg(feature = "alloc")]
 pub use macd::*;
 pub use max::*;
+#[cfg(feature = "alloc")]
 pub use max_index::*;
+pub use mcginley_dynamic::*;
 pub use min::*;
+#[cfg(feature = "alloc")]
 pub use min_index::*;
+pub use momentum::*;
+#[cfg(feature = "alloc")]
+pub use money_flow_index::*;
+pub use natr::*;
+pub use obv::*;
+pub use parabolic_sar::*;
+#[cfg(feature = "alloc")]
+pub use percent_rank::*;
+#[cfg(feature = "alloc")]
+pub use ppo::*;
 pub use rma::*;
+#[cfg(feature = "alloc")]
+pub use roc::*;
+#[cfg(feature = "alloc")]
+pub use rolling_beta::*;
+#[cfg(feature = "alloc")]
+pub use rolling_correlation::*;
+#[cfg(feature = "alloc")]
+pub use rolling_covariance::*;
+#[cfg(feature = "alloc")]
+pub use rolling_kurtosis::*;
+#[cfg(feature = "alloc")]
+pub use rolling_median::*;
+#[cfg(feature = "alloc")]
+pub use rolling_quantile::*;
+#[cfg(feature = "alloc")]
+pub use rolling_skewness::*;
+#[cfg(feature = "alloc")]
+pub use rolling_z_score::*;
 pub use rsi::*;
 pub use sma::*;
 pub use standard_deviation::*;
+#[cfg(feature = "alloc")]
+pub use stoch_rsi::*;
+#[cfg(feature = "alloc")]
 pub use stochastics::*;
+pub use t3::*;
+pub use tema::*;
+#[cfg(feature = "alloc")]
+pub use time_series_forecast::*;
+#[cfg(feature = "alloc")]
+pub use trima::*;
+pub use trix::*;
+pub use true_range::*;
+pub use tsi::*;
+#[cfg(feature = "alloc")]
+pub use vidya::*;
 pub use vwap::*;
 pub use vwma::*;
+#[cfg(feature = "alloc")]
+pub use williams_r::*;
+pub use wma::*;
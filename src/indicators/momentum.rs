@@ -0,0 +1,172 @@
+#[cfg(feature = "alloc")]
+use crate::Warmup;
+use crate::{
+    storage::check_capacity, Current, DefaultStorage, Indicator, InvalidRangeError, Lookback, Next,
+    Number, Parameter, Price, Range, Reset, Result, Ring, Storage, WarmupPeriod,
+};
+
+/// Momentum
+///
+/// Difference between the price and the price `period` bars ago
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, S::Ring<T>: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, S::Ring<T>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Momentum<T: Number = f64, S: Storage = DefaultStorage> {
+    period: usize,
+    ring: S::Ring<T>,
+    count: usize,
+    current: Option<T>,
+}
+impl Momentum {
+    pub const DEFAULT_PERIOD: usize = 10;
+
+    #[cfg(feature = "alloc")]
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+}
+impl<T: Number, S: Storage> Momentum<T, S> {
+    /// Same as `new`, in any [`Number`] type and [`Storage`]
+    pub fn with_period(period: usize) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            check_capacity::<S>(period, period + 1)?;
+            Ok(Self {
+                period,
+                ring: Ring::with_capacity(period + 1),
+                count: 0,
+                current: None,
+            })
+        }
+    }
+
+    fn _next(&mut self, input: T) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        if self.ring.is_empty() {
+            for _ in 0..=self.period {
+                self.ring.push_back(input);
+            }
+        } else {
+            let _ = self.ring.pop_front();
+            self.ring.push_back(input);
+        }
+
+        let momentum = input - *self.ring.front().unwrap();
+        self.current = momentum.into();
+        momentum
+    }
+
+    /// Price `period` bars before the latest input
+    pub(crate) fn past(&self) -> Option<T> {
+        self.ring.front().copied()
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T: Number> Default for Momentum<T> {
+    fn default() -> Self {
+        Self::with_period(Momentum::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl<T: Number, S: Storage> Indicator for Momentum<T, S> {
+    type Output = T;
+}
+impl<T: Number, S: Storage> Current for Momentum<T, S> {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl_next_for_numbers!(storage Momentum);
+impl<T: Number, S: Storage, Input: Price<T>> Next<&Input> for Momentum<T, S> {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl<T: Number, S: Storage> Reset for Momentum<T, S> {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ring.clear();
+        self.current = None;
+    }
+}
+impl<T: Number, S: Storage> WarmupPeriod for Momentum<T, S> {
+    fn warmup_period(&self) -> usize {
+        self.period
+    }
+}
+impl<T: Number, S: Storage> Lookback for Momentum<T, S> {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[0.0, 2.0, 1.0, 4.0, 1.0, 5.0, 1.0];
+
+    test_indicator! {
+        new: Momentum::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: Momentum::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Momentum = Default::default();
+    }
+}
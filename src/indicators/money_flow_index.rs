@@ -0,0 +1,187 @@
+use crate::{
+    Close, Current, High, Indicator, Lookback, Low, Next, Reset, Result, Sma, Volume, Warmup,
+    WarmupPeriod,
+};
+
+/// Money Flow Index
+///
+/// Range in \[0.0, 1.0\]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoneyFlowIndex {
+    positive_flow: Sma,
+    negative_flow: Sma,
+    prev_typical_price: Option<f64>,
+    count: usize,
+}
+impl MoneyFlowIndex {
+    pub const DEFAULT_PERIOD: usize = 14;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let positive_flow = Sma::new(period)?;
+        let negative_flow = Sma::new(period)?;
+        Ok(Self {
+            positive_flow,
+            negative_flow,
+            prev_typical_price: None,
+            count: 0,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(
+        &mut self,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: f64,
+    ) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let typical_price = (high + low + close) / 3.0;
+        let raw_money_flow = typical_price * volume;
+
+        let (positive_flow, negative_flow) = match self.prev_typical_price {
+            Some(prev) if prev < typical_price => (raw_money_flow, 0.0),
+            Some(prev) if typical_price < prev => (0.0, raw_money_flow),
+            _ => (0.0, 0.0),
+        };
+        let _ = self.positive_flow.next(positive_flow);
+        let _ = self.negative_flow.next(negative_flow);
+        self.prev_typical_price = typical_price.into();
+
+        self.current().unwrap()
+    }
+}
+impl Default for MoneyFlowIndex {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for MoneyFlowIndex {
+    type Output = f64;
+}
+impl Current for MoneyFlowIndex {
+    fn current(&self) -> Option<Self::Output> {
+        match (self.positive_flow.current(), self.negative_flow.current()) {
+            (Some(positive), Some(negative)) => {
+                if positive + negative <= 0.0 {
+                    0.5
+                } else {
+                    positive / (positive + negative)
+                }
+            }
+            .into(),
+            _ => None,
+        }
+    }
+}
+impl Next<(f64, f64, f64, f64)> for MoneyFlowIndex {
+    fn next(&mut self, (high, low, close, volume): (f64, f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close, volume)
+    }
+}
+impl<Input: High + Low + Close + Volume> Next<&Input> for MoneyFlowIndex {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close(), input.volume())
+    }
+}
+impl Reset for MoneyFlowIndex {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.positive_flow.reset();
+        self.negative_flow.reset();
+        self.prev_typical_price = None;
+    }
+}
+impl WarmupPeriod for MoneyFlowIndex {
+    fn warmup_period(&self) -> usize {
+        // The first bar has no previous typical price to compare with
+        self.positive_flow.warmup_period() + 1
+    }
+}
+impl Lookback for MoneyFlowIndex {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+    impl Volume for TestItem {
+        fn volume(&self) -> f64 {
+            self.3
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0, 100.0),
+            (11.0, 9.0, 10.5, 150.0),
+            (12.0, 10.0, 11.0, 120.0),
+            (11.5, 9.5, 10.0, 200.0),
+            (10.0, 8.5, 9.0, 180.0),
+            (12.0, 9.0, 11.5, 250.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close, volume)| TestItem(high, low, close, volume))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[0.5, 1.0, 1.0, 0.57923312, 0.26207809, 0.42153048];
+
+    test_indicator! {
+        new: MoneyFlowIndex::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: MoneyFlowIndex::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close(), x.volume())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: MoneyFlowIndex = Default::default();
+    }
+}
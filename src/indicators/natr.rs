@@ -0,0 +1,209 @@
+use crate::{
+    Atr, Close, Current, High, Indicator, Lookback, Low, Next, Reset, Result, Rma, Seed, Warmup,
+    WarmupPeriod,
+};
+
+/// Normalized Average True Range
+///
+/// ATR divided by the close price, expressed as a ratio (not as a percentage).
+/// Outputs 0.0 when the close is 0.0, as the ratio is undefined there.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Natr<Ma = Rma> {
+    atr: Atr<Ma>,
+    close: Option<f64>,
+}
+impl Natr {
+    pub const DEFAULT_PERIOD: usize = Atr::DEFAULT_PERIOD;
+
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_seed(period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(period: usize, seed: Seed) -> Result<Self> {
+        let atr = Atr::with_seed(period, seed)?;
+        Ok(Self { atr, close: None })
+    }
+}
+impl<Ma> Natr<Ma>
+where
+    Ma: Indicator<Output = f64> + Next<f64>,
+{
+    /// Create a new NATR whose ATR is smoothed with the given moving average.
+    pub fn with_moving_average(ma: Ma) -> Self {
+        Self {
+            atr: Atr::with_moving_average(ma),
+            close: None,
+        }
+    }
+
+    fn _next(&mut self, high: f64, low: f64, close: f64) -> <Self as Indicator>::Output {
+        let atr = self.atr.next((high, low, close));
+        self.close = close.into();
+        Self::normalize(atr, close)
+    }
+}
+impl<Ma> Natr<Ma> {
+    fn normalize(atr: f64, close: f64) -> f64 {
+        if close == 0.0 {
+            0.0
+        } else {
+            atr / close
+        }
+    }
+}
+impl Default for Natr {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl<Ma> Indicator for Natr<Ma>
+where
+    Ma: Indicator<Output = f64>,
+{
+    type Output = f64;
+}
+impl<Ma> Current for Natr<Ma>
+where
+    Ma: Indicator<Output = f64> + Current,
+{
+    fn current(&self) -> Option<Self::Output> {
+        match (self.atr.current(), self.close) {
+            (Some(atr), Some(close)) => Self::normalize(atr, close).into(),
+            _ => None,
+        }
+    }
+}
+impl<Ma> Next<(f64, f64, f64)> for Natr<Ma>
+where
+    Ma: Indicator<Output = f64> + Next<f64>,
+{
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close)
+    }
+}
+impl<Ma, Input> Next<&Input> for Natr<Ma>
+where
+    Ma: Indicator<Output = f64> + Next<f64>,
+    Input: High + Low + Close,
+{
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close())
+    }
+}
+impl<Ma> Reset for Natr<Ma>
+where
+    Ma: Reset,
+{
+    fn reset(&mut self) {
+        self.atr.reset();
+        self.close = None;
+    }
+}
+impl<Ma> WarmupPeriod for Natr<Ma>
+where
+    Ma: WarmupPeriod,
+{
+    fn warmup_period(&self) -> usize {
+        self.atr.warmup_period()
+    }
+}
+impl<Ma> Lookback for Natr<Ma>
+where
+    Ma: Lookback,
+{
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.0),
+            (12.0, 10.0, 11.0),
+            (15.0, 13.0, 14.0),
+            (13.0, 12.0, 12.5),
+            (12.0, 10.0, 11.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        0.22222222, 0.2, 0.18181818, 0.19047619, 0.19555556, 0.22390572,
+    ];
+
+    test_indicator! {
+        new: Natr::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: Natr::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Natr = Default::default();
+    }
+
+    #[test]
+    fn zero_close() -> crate::Result<()> {
+        let mut natr = Natr::new(PERIOD)?;
+
+        let _ = natr.next((10.0, 8.0, 9.0));
+        assert_eq!(natr.next((1.0, 0.0, 0.0)), 0.0);
+        assert_eq!(natr.current(), Some(0.0));
+        assert!(natr.next((10.0, 8.0, 9.0)).is_finite());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Close, Current, Indicator, Lookback, Next, Reset, Volume, Warmup, WarmupPeriod};
+
+/// On Balance Volume
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Obv {
+    prev_close: Option<f64>,
+    current: Option<f64>,
+}
+impl Obv {
+    pub fn new() -> Self {
+        Self {
+            prev_close: None,
+            current: None,
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(_warmup: W) -> W::Apply<Self> {
+        W::apply(Self::new())
+    }
+
+    fn _next(&mut self, close: f64, volume: f64) -> <Self as Indicator>::Output {
+        match (&mut self.current, self.prev_close) {
+            (Some(current), Some(prev_close)) => {
+                if prev_close < close {
+                    *current += volume;
+                } else if close < prev_close {
+                    *current -= volume;
+                }
+            }
+            _ => {
+                self.current = 0.0.into();
+            }
+        }
+        self.prev_close = close.into();
+        self.current().unwrap()
+    }
+}
+impl Default for Obv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for Obv {
+    type Output = f64;
+}
+impl Current for Obv {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<(f64, f64)> for Obv {
+    fn next(&mut self, (close, volume): (f64, f64)) -> Self::Output {
+        self._next(close, volume)
+    }
+}
+impl<Input: Close + Volume> Next<&Input> for Obv {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.close(), input.volume())
+    }
+}
+impl Reset for Obv {
+    fn reset(&mut self) {
+        self.prev_close = None;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Obv {
+    fn warmup_period(&self) -> usize {
+        0
+    }
+}
+impl Lookback for Obv {
+    fn is_ready(&self) -> bool {
+        self.current.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64);
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Volume for TestItem {
+        fn volume(&self) -> f64 {
+            self.1
+        }
+    }
+
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (9.0, 100.0),
+            (10.5, 150.0),
+            (11.0, 120.0),
+            (10.0, 200.0),
+            (9.0, 180.0),
+            (11.5, 250.0),
+        ]
+        .into_iter()
+        .map(|(close, volume)| TestItem(close, volume))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[0.0, 150.0, 270.0, 70.0, -110.0, 140.0];
+
+    test_indicator! {
+        new: crate::Result::Ok(Obv::new()),
+        inputs: INPUTS.iter().map(|x| (x.close(), x.volume())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.close(), x.volume())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.close(), x.volume())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.close(), x.volume())),
+            },
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use crate::{
+    Current, High, Indicator, InvalidBinaryRelationError, InvalidRangeError, Lookback, Low, Next,
+    Parameter, Range, Reset, Result, Warmup, WarmupPeriod,
+};
+
+/// Parabolic Stop And Reverse
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParabolicSar {
+    initial_acceleration: f64,
+    acceleration_step: f64,
+    max_acceleration: f64,
+    extreme_point: f64,
+    acceleration: f64,
+    prev_high_low: Option<(f64, f64)>,
+    prev2_high_low: Option<(f64, f64)>,
+    current: Option<ParabolicSarOutput>,
+}
+impl ParabolicSar {
+    pub const DEFAULT_INITIAL_ACCELERATION: f64 = 0.02;
+    pub const DEFAULT_ACCELERATION_STEP: f64 = 0.02;
+    pub const DEFAULT_MAX_ACCELERATION: f64 = 0.2;
+
+    pub fn new(
+        initial_acceleration: f64,
+        acceleration_step: f64,
+        max_acceleration: f64,
+    ) -> Result<Self> {
+        for (name, value) in [
+            ("initial_acceleration", initial_acceleration),
+            ("acceleration_step", acceleration_step),
+            ("max_acceleration", max_acceleration),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(InvalidRangeError {
+                    param: Parameter::new(name, value),
+                    range: Range::BothBounded { min: 0.0, max: 1.0 },
+                }
+                .into());
+            }
+        }
+        if acceleration_step == 0.0 {
+            // The acceleration factor would never move off `initial_acceleration`
+            return Err(InvalidRangeError {
+                param: Parameter::new("acceleration_step", acceleration_step),
+                range: Range::LeftOpen { min: 0.0, max: 1.0 },
+            }
+            .into());
+        }
+        if max_acceleration < initial_acceleration {
+            return Err(InvalidBinaryRelationError {
+                operator: "<=",
+                lhs: Parameter::new("initial_acceleration", initial_acceleration),
+                rhs: Parameter::new("max_acceleration", max_acceleration),
+            }
+            .into());
+        }
+
+        Ok(Self {
+            initial_acceleration,
+            acceleration_step,
+            max_acceleration,
+            extreme_point: 0.0,
+            acceleration: initial_acceleration,
+            prev_high_low: None,
+            prev2_high_low: None,
+            current: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        initial_acceleration: f64,
+        acceleration_step: f64,
+        max_acceleration: f64,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::new(initial_acceleration, acceleration_step, max_acceleration).map(W::apply)
+    }
+
+    fn _next(&mut self, high: f64, low: f64) -> <Self as Indicator>::Output {
+        match (&mut self.current, self.prev_high_low) {
+            (Some(current), Some((prev_high, prev_low))) => {
+                let mut sar = current.sar + self.acceleration * (self.extreme_point - current.sar);
+                current.is_reversal = false;
+
+                match current.trend {
+                    Trend::Up => {
+                        sar = sar.min(prev_low);
+                        if let Some((_, prev2_low)) = self.prev2_high_low {
+                            sar = sar.min(prev2_low);
+                        }
+
+                        if low < sar {
+                            current.trend = Trend::Down;
+                            current.is_reversal = true;
+                            sar = self.extreme_point.max(high);
+                            self.extreme_point = low;
+                            self.acceleration = self.initial_acceleration;
+                        } else if self.extreme_point < high {
+                            self.extreme_point = high;
+                            self.acceleration = (self.acceleration + self.acceleration_step)
+                                .min(self.max_acceleration);
+                        }
+                    }
+                    Trend::Down => {
+                        sar = sar.max(prev_high);
+                        if let Some((prev2_high, _)) = self.prev2_high_low {
+                            sar = sar.max(prev2_high);
+                        }
+
+                        if sar < high {
+                            current.trend = Trend::Up;
+                            current.is_reversal = true;
+                            sar = self.extreme_point.min(low);
+                            self.extreme_point = high;
+                            self.acceleration = self.initial_acceleration;
+                        } else if low < self.extreme_point {
+                            self.extreme_point = low;
+                            self.acceleration = (self.acceleration + self.acceleration_step)
+                                .min(self.max_acceleration);
+                        }
+                    }
+                }
+
+                current.sar = sar;
+            }
+            _ => {
+                self.extreme_point = high;
+                self.acceleration = self.initial_acceleration;
+                self.current = ParabolicSarOutput {
+                    sar: low,
+                    trend: Trend::Up,
+                    is_reversal: false,
+                }
+                .into();
+            }
+        }
+
+        self.prev2_high_low = self.prev_high_low;
+        self.prev_high_low = (high, low).into();
+
+        self.current().unwrap()
+    }
+}
+impl Default for ParabolicSar {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_INITIAL_ACCELERATION,
+            Self::DEFAULT_ACCELERATION_STEP,
+            Self::DEFAULT_MAX_ACCELERATION,
+        )
+        .unwrap()
+    }
+}
+
+impl Indicator for ParabolicSar {
+    type Output = ParabolicSarOutput;
+}
+impl Current for ParabolicSar {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<(f64, f64)> for ParabolicSar {
+    fn next(&mut self, (high, low): (f64, f64)) -> Self::Output {
+        self._next(high, low)
+    }
+}
+impl<Input: High + Low> Next<&Input> for ParabolicSar {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low())
+    }
+}
+impl Reset for ParabolicSar {
+    fn reset(&mut self) {
+        self.extreme_point = 0.0;
+        self.acceleration = self.initial_acceleration;
+        self.prev_high_low = None;
+        self.prev2_high_low = None;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for ParabolicSar {
+    fn warmup_period(&self) -> usize {
+        // The first bar only starts the trend
+        1
+    }
+}
+impl Lookback for ParabolicSar {
+    fn is_ready(&self) -> bool {
+        self.prev2_high_low.is_some()
+    }
+}
+
+/// Trend direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Trend {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParabolicSarOutput {
+    pub sar: f64,
+    pub trend: Trend,
+    /// `true` on the bar where the trend has flipped
+    pub is_reversal: bool,
+}
+impl From<(f64, Trend, bool)> for ParabolicSarOutput {
+    fn from((sar, trend, is_reversal): (f64, Trend, bool)) -> Self {
+        Self {
+            sar,
+            trend,
+            is_reversal,
+        }
+    }
+}
+impl From<ParabolicSarOutput> for (f64, Trend, bool) {
+    fn from(x: ParabolicSarOutput) -> Self {
+        (x.sar, x.trend, x.is_reversal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for ParabolicSarOutput {
+        fn round(self) -> Self {
+            Self {
+                sar: Round::round(self.sar),
+                ..self
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+
+    const INITIAL_ACCELERATION: f64 = 0.1;
+    const ACCELERATION_STEP: f64 = 0.1;
+    const MAX_ACCELERATION: f64 = 0.3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 9.0),
+            (11.0, 10.0),
+            (12.0, 11.0),
+            (12.5, 11.5),
+            (11.0, 9.5),
+            (10.0, 8.5),
+            (9.5, 8.0),
+            (11.0, 9.0),
+            (12.5, 10.5),
+            (13.0, 11.5),
+        ]
+        .into_iter()
+        .map(|(high, low)| TestItem(high, low))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[ParabolicSarOutput]>> = SyncLazy::new(|| {
+        [
+            (9.0, Trend::Up, false),
+            (9.0, Trend::Up, false),
+            (9.0, Trend::Up, false),
+            (9.9, Trend::Up, false),
+            (12.5, Trend::Down, true),
+            (12.5, Trend::Down, false),
+            (11.7, Trend::Down, false),
+            (8.0, Trend::Up, true),
+            (8.0, Trend::Up, false),
+            (8.9, Trend::Up, false),
+        ]
+        .into_iter()
+        .map(ParabolicSarOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: ParabolicSar::new(INITIAL_ACCELERATION, ACCELERATION_STEP, MAX_ACCELERATION),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    ParabolicSar::new(-0.1, 0.1, 0.2),
+                    ParabolicSar::new(0.1, -0.1, 0.2),
+                    ParabolicSar::new(0.1, 0.0, 0.2),
+                    ParabolicSar::new(0.1, 0.1, 1.1),
+                    ParabolicSar::new(0.3, 0.1, 0.2),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: ParabolicSar = Default::default();
+    }
+
+    #[test]
+    fn sar_does_not_penetrate_price() -> crate::Result<()> {
+        let mut parabolic_sar = ParabolicSar::default();
+
+        for x in RANDOM_DATA.iter() {
+            let output = parabolic_sar.next(x);
+            match output.trend {
+                Trend::Up => assert!(output.sar <= x.low()),
+                Trend::Down => assert!(x.high() <= output.sar),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    collections::OrderStatisticTree, Current, Indicator, InvalidRangeError, Lookback, Next,
+    Parameter, Price, Range, Reset, Result, Warmup, WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Percent Rank
+///
+/// Fraction of the previous `period` inputs that are less than the input.
+/// Updated in O(log n) per bar.
+///
+/// Range in \[0.0, 1.0\]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PercentRank {
+    period: usize,
+    ring: VecDeque<f64>,
+    sorted: OrderStatisticTree,
+    count: usize,
+    current: Option<f64>,
+}
+impl PercentRank {
+    pub const DEFAULT_PERIOD: usize = 100;
+
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                period,
+                ring: VecDeque::with_capacity(period),
+                sorted: OrderStatisticTree::with_capacity(period),
+                count: 0,
+                current: None,
+            })
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        if self.ring.is_empty() {
+            for _ in 0..self.period {
+                self.ring.push_back(input);
+                self.sorted.insert(input);
+            }
+        }
+
+        let (below, _) = self.sorted.less_than(input);
+        let percent_rank = below as f64 / self.period as f64;
+
+        let old_val = self.ring.pop_front().unwrap();
+        let _ = self.sorted.remove(old_val);
+        self.ring.push_back(input);
+        self.sorted.insert(input);
+
+        self.current = percent_rank.into();
+        percent_rank
+    }
+}
+impl Default for PercentRank {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for PercentRank {
+    type Output = f64;
+}
+impl Current for PercentRank {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for PercentRank {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for PercentRank {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for PercentRank {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ring.clear();
+        self.sorted.clear();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for PercentRank {
+    fn warmup_period(&self) -> usize {
+        self.period
+    }
+}
+impl Lookback for PercentRank {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 110.0, 105.0, 99.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[0.0, 1.0, 0.75, 1.0, 0.75, 1.0, 0.75, 0.0];
+
+    test_indicator! {
+        new: PercentRank::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: PercentRank::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: PercentRank = Default::default();
+    }
+}
@@ -0,0 +1,256 @@
+use super::macd::check_short_long;
+use crate::operators::Diff;
+use crate::{
+    Current, Ema, Indicator, Lookback, Macd, Next, Price, Reset, Result, Seed, Sma, Warmup,
+    WarmupPeriod,
+};
+
+/// Percentage Price Oscillator
+///
+/// Difference between a short and a long [`Ema`] divided by the long one, expressed as a ratio
+/// (not as a percentage), with a signal line and a histogram.
+/// Outputs 0.0 while the long EMA is 0.0, as the ratio is undefined there.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ppo {
+    diff: Diff<Ema, Ema>,
+    signal: Sma,
+    count: usize,
+}
+impl Ppo {
+    pub const DEFAULT_SHORT_PERIOD: usize = Macd::DEFAULT_SHORT_PERIOD;
+    pub const DEFAULT_LONG_PERIOD: usize = Macd::DEFAULT_LONG_PERIOD;
+    pub const DEFAULT_SIGNAL_PERIOD: usize = Macd::DEFAULT_SIGNAL_PERIOD;
+
+    pub fn new(short_period: usize, long_period: usize, signal_period: usize) -> Result<Self> {
+        Self::with_seed(short_period, long_period, signal_period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        short_period: usize,
+        long_period: usize,
+        signal_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(short_period, long_period, signal_period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(
+        short_period: usize,
+        long_period: usize,
+        signal_period: usize,
+        seed: Seed,
+    ) -> Result<Self> {
+        check_short_long(short_period, long_period)?;
+
+        Ok(Self {
+            diff: Diff::new(
+                Ema::with_seed(short_period, seed)?,
+                Ema::with_seed(long_period, seed)?,
+            ),
+            signal: Sma::new(signal_period)?,
+            count: 0,
+        })
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let diff = self.diff.next((input, input));
+        let _ = self
+            .signal
+            .next(Self::ratio(diff, self.diff.rhs().current().unwrap()));
+        self.current().unwrap()
+    }
+
+    fn ratio(diff: f64, long: f64) -> f64 {
+        if long == 0.0 {
+            0.0
+        } else {
+            diff / long
+        }
+    }
+}
+impl Default for Ppo {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_SHORT_PERIOD,
+            Self::DEFAULT_LONG_PERIOD,
+            Self::DEFAULT_SIGNAL_PERIOD,
+        )
+        .unwrap()
+    }
+}
+
+impl Indicator for Ppo {
+    type Output = PpoOutput;
+}
+impl Current for Ppo {
+    fn current(&self) -> Option<Self::Output> {
+        match (
+            self.diff.current(),
+            self.diff.rhs().current(),
+            self.signal.current(),
+        ) {
+            (Some(diff), Some(long), Some(signal)) => {
+                let ppo = Self::ratio(diff, long);
+                PpoOutput {
+                    ppo,
+                    signal,
+                    histogram: ppo - signal,
+                }
+                .into()
+            }
+            _ => None,
+        }
+    }
+}
+impl Next<f64> for Ppo {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Ppo {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Ppo {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.diff.reset();
+        self.signal.reset();
+    }
+}
+impl WarmupPeriod for Ppo {
+    fn warmup_period(&self) -> usize {
+        // The long EMA is the last to warm up
+        self.diff.rhs().warmup_period() + self.signal.warmup_period()
+    }
+}
+impl Lookback for Ppo {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PpoOutput {
+    pub ppo: f64,
+    pub signal: f64,
+    pub histogram: f64,
+}
+impl From<(f64, f64, f64)> for PpoOutput {
+    fn from((ppo, signal, histogram): (f64, f64, f64)) -> Self {
+        Self {
+            ppo,
+            signal,
+            histogram,
+        }
+    }
+}
+impl From<PpoOutput> for (f64, f64, f64) {
+    fn from(x: PpoOutput) -> Self {
+        (x.ppo, x.signal, x.histogram)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl Round for PpoOutput {
+        fn round(self) -> Self {
+            Self {
+                ppo: Round::round(self.ppo),
+                signal: Round::round(self.signal),
+                histogram: Round::round(self.histogram),
+            }
+        }
+    }
+
+    const SHORT_PERIOD: usize = 2;
+    const LONG_PERIOD: usize = 4;
+    const SIGNAL_PERIOD: usize = 2;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[PpoOutput]>> = SyncLazy::new(|| {
+        [
+            (0.0, 0.0, 0.0),
+            (0.00529101, 0.0026455, 0.0026455),
+            (0.00229095, 0.00379098, -0.00150003),
+            (0.00890096, 0.00559595, 0.003305),
+            (0.00522602, 0.00706349, -0.00183747),
+            (0.01076213, 0.00799407, 0.00276805),
+            (0.00641918, 0.00859065, -0.00217147),
+        ]
+        .into_iter()
+        .map(PpoOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: Ppo::new(SHORT_PERIOD, LONG_PERIOD, SIGNAL_PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    Ppo::new(0, 2, 2),
+                    Ppo::new(3, 3, 2),
+                    Ppo::new(2, 3, 0),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Ppo = Default::default();
+    }
+
+    #[test]
+    fn zero_long_ema() -> crate::Result<()> {
+        let mut ppo = Ppo::new(SHORT_PERIOD, LONG_PERIOD, SIGNAL_PERIOD)?;
+
+        assert_eq!(ppo.next(0.0), PpoOutput::from((0.0, 0.0, 0.0)));
+        assert_eq!(ppo.next(0.0), PpoOutput::from((0.0, 0.0, 0.0)));
+        for _ in 0..10 {
+            let output = ppo.next(1.0);
+            assert!(output.ppo.is_finite());
+            assert!(output.signal.is_finite());
+            assert!(output.histogram.is_finite());
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,33 @@
-use crate::{Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result};
+use crate::{
+    Current, Indicator, InvalidRangeError, Lookback, Next, Number, Parameter, Price, Range, Reset,
+    Result, Seed, Warmup, WarmupPeriod,
+};
 
 /// Running Moving Average (a.k.a Modified Moving Average)
 #[derive(Debug, Clone)]
-pub struct Rma {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rma<T = f64> {
     period: usize,
-    current: Option<f64>,
+    seed: Seed,
+    count: usize,
+    current: Option<T>,
 }
 impl Rma {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, W::SEED).map(W::apply)
+    }
+}
+impl<T: Number> Rma<T> {
+    /// Same as `new`, in any [`Number`] type
+    pub fn with_period(period: usize) -> Result<Self> {
+        Self::with_seed(period, Seed::First)
+    }
+
+    pub(crate) fn with_seed(period: usize, seed: Seed) -> Result<Self> {
         if period < 2 {
             Err(InvalidRangeError {
                 param: Parameter::new("period", period),
@@ -17,15 +37,23 @@ impl Rma {
         } else {
             Ok(Self {
                 period,
+                seed,
+                count: 0,
                 current: None,
             })
         }
     }
 
-    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+    fn _next(&mut self, input: T) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         match &mut self.current {
             Some(current) => {
-                *current += (input - *current) / self.period as f64;
+                // Until `period` inputs have arrived, a mean seed is the cumulative average
+                let period = match self.seed {
+                    Seed::First => self.period,
+                    Seed::Mean => self.count.min(self.period),
+                };
+                *current += (input - *current) / T::from_usize(period);
             }
             None => {
                 self.current = input.into();
@@ -35,29 +63,36 @@ impl Rma {
     }
 }
 
-impl Indicator for Rma {
-    type Output = f64;
+impl<T: Number> Indicator for Rma<T> {
+    type Output = T;
 }
-impl Current for Rma {
+impl<T: Number> Current for Rma<T> {
     fn current(&self) -> Option<Self::Output> {
         self.current
     }
 }
-impl Next<f64> for Rma {
-    fn next(&mut self, input: f64) -> Self::Output {
-        self._next(input)
-    }
-}
-impl<Input: Price> Next<&Input> for Rma {
+impl_next_for_numbers!(Rma);
+impl<T: Number, Input: Price<T>> Next<&Input> for Rma<T> {
     fn next(&mut self, input: &Input) -> Self::Output {
         self._next(input.price())
     }
 }
-impl Reset for Rma {
+impl<T: Number> Reset for Rma<T> {
     fn reset(&mut self) {
+        self.count = 0;
         self.current = None;
     }
 }
+impl<T: Number> WarmupPeriod for Rma<T> {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl<T: Number> Lookback for Rma<T> {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -102,6 +137,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
 }
@@ -0,0 +1,146 @@
+use crate::{
+    Current, Indicator, Lookback, Momentum, Next, Price, Reset, Result, Warmup, WarmupPeriod,
+};
+
+/// Rate of Change
+///
+/// [`Momentum`] divided by the price `period` bars ago, expressed as a ratio (not as a percentage).
+/// Outputs 0.0 when that price is 0.0, as the ratio is undefined there.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Roc {
+    momentum: Momentum,
+    current: Option<f64>,
+}
+impl Roc {
+    pub const DEFAULT_PERIOD: usize = Momentum::DEFAULT_PERIOD;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let momentum = Momentum::new(period)?;
+        Ok(Self {
+            momentum,
+            current: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        let momentum = self.momentum.next(input);
+        let past = self.momentum.past().unwrap();
+        let roc = if past == 0.0 { 0.0 } else { momentum / past };
+        self.current = roc.into();
+        roc
+    }
+}
+impl Default for Roc {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for Roc {
+    type Output = f64;
+}
+impl Current for Roc {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for Roc {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Roc {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Roc {
+    fn reset(&mut self) {
+        self.momentum.reset();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Roc {
+    fn warmup_period(&self) -> usize {
+        self.momentum.warmup_period()
+    }
+}
+impl Lookback for Roc {
+    fn is_ready(&self) -> bool {
+        self.momentum.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[0.0, 0.02, 0.01, 0.04, 0.00980392, 0.04950495, 0.00961538];
+
+    test_indicator! {
+        new: Roc::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: Roc::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Roc = Default::default();
+    }
+
+    #[test]
+    fn zero_past_price() -> crate::Result<()> {
+        let mut roc = Roc::new(PERIOD)?;
+
+        assert_eq!(roc.next(0.0), 0.0);
+        assert_eq!(roc.next(1.0), 0.0);
+        assert_eq!(roc.next(2.0), 0.0);
+        assert_eq!(roc.next(3.0), 0.0);
+        // The past price is 1.0 from here on
+        assert_eq!(roc.next(4.0), 3.0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    Current, Indicator, Lookback, Next, Reset, Result, RollingCovariance, Warmup, WarmupPeriod,
+};
+
+/// Rolling Beta
+///
+/// Takes `(asset, benchmark)` pairs, typically returns, and outputs
+/// the covariance of the last `period` pairs divided by the variance of the benchmark.
+/// Outputs 0.0 while the benchmark is flat.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingBeta {
+    covariance: RollingCovariance,
+}
+impl RollingBeta {
+    pub const DEFAULT_PERIOD: usize = RollingCovariance::DEFAULT_PERIOD;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let covariance = RollingCovariance::new(period)?;
+        Ok(Self { covariance })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, asset: f64, benchmark: f64) -> <Self as Indicator>::Output {
+        let _ = self.covariance.next((asset, benchmark));
+        self.current().unwrap()
+    }
+}
+impl Default for RollingBeta {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for RollingBeta {
+    type Output = f64;
+}
+impl Current for RollingBeta {
+    fn current(&self) -> Option<Self::Output> {
+        self.covariance
+            .moments()
+            .map(|m| if m.syy <= 0.0 { 0.0 } else { m.sxy / m.syy })
+    }
+}
+impl Next<(f64, f64)> for RollingBeta {
+    fn next(&mut self, (asset, benchmark): (f64, f64)) -> Self::Output {
+        self._next(asset, benchmark)
+    }
+}
+impl Reset for RollingBeta {
+    fn reset(&mut self) {
+        self.covariance.reset();
+    }
+}
+impl WarmupPeriod for RollingBeta {
+    fn warmup_period(&self) -> usize {
+        self.covariance.warmup_period()
+    }
+}
+impl Lookback for RollingBeta {
+    fn is_ready(&self) -> bool {
+        self.covariance.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helper::*, High, Low};
+
+    const PERIOD: usize = 3;
+    static INPUTS: &[(f64, f64)] = &[
+        (1.0, 2.0),
+        (2.0, 1.0),
+        (4.0, 5.0),
+        (3.0, 3.0),
+        (5.0, 8.0),
+        (4.0, 4.0),
+    ];
+    static OUTPUTS: &[f64] = &[0.0, -1.0, 0.61538462, 0.5, 0.39473684, 0.35714286];
+
+    test_indicator! {
+        new: RollingBeta::new(PERIOD),
+        inputs: INPUTS.iter().copied(),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: RollingBeta::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: RollingBeta = Default::default();
+    }
+
+    #[test]
+    fn flat_after_random() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut beta = RollingBeta::new(PERIOD)?;
+
+        // An offset leaves rounding residue in the incremental sums
+        for x in RANDOM_DATA.iter() {
+            let _ = beta.next((x.high() + 1e4, x.low() + 1e4));
+        }
+        // Only the benchmark goes flat
+        for x in RANDOM_DATA.iter().take(PERIOD) {
+            let _ = beta.next((x.high() + 1e4, 1e4 + 0.1));
+        }
+        assert_eq!(beta.current(), Some(0.0));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    Current, Indicator, Lookback, Next, Reset, Result, RollingCovariance, Warmup, WarmupPeriod,
+};
+
+/// Rolling Correlation
+///
+/// Pearson correlation coefficient of the last `period` pairs of `(x, y)`.
+///
+/// Range in \[-1.0, 1.0\]; outputs 0.0 while either series is flat.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingCorrelation {
+    covariance: RollingCovariance,
+}
+impl RollingCorrelation {
+    pub const DEFAULT_PERIOD: usize = RollingCovariance::DEFAULT_PERIOD;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let covariance = RollingCovariance::new(period)?;
+        Ok(Self { covariance })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, x: f64, y: f64) -> <Self as Indicator>::Output {
+        let _ = self.covariance.next((x, y));
+        self.current().unwrap()
+    }
+}
+impl Default for RollingCorrelation {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for RollingCorrelation {
+    type Output = f64;
+}
+impl Current for RollingCorrelation {
+    fn current(&self) -> Option<Self::Output> {
+        self.covariance.moments().map(|m| {
+            if m.sxx <= 0.0 || m.syy <= 0.0 {
+                0.0
+            } else {
+                (m.sxy / (m.sxx * m.syy).sqrt()).clamp(-1.0, 1.0)
+            }
+        })
+    }
+}
+impl Next<(f64, f64)> for RollingCorrelation {
+    fn next(&mut self, (x, y): (f64, f64)) -> Self::Output {
+        self._next(x, y)
+    }
+}
+impl Reset for RollingCorrelation {
+    fn reset(&mut self) {
+        self.covariance.reset();
+    }
+}
+impl WarmupPeriod for RollingCorrelation {
+    fn warmup_period(&self) -> usize {
+        self.covariance.warmup_period()
+    }
+}
+impl Lookback for RollingCorrelation {
+    fn is_ready(&self) -> bool {
+        self.covariance.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helper::*, High, Low};
+
+    const PERIOD: usize = 3;
+    static INPUTS: &[(f64, f64)] = &[
+        (1.0, 2.0),
+        (2.0, 1.0),
+        (4.0, 5.0),
+        (3.0, 3.0),
+        (5.0, 8.0),
+        (4.0, 4.0),
+    ];
+    static OUTPUTS: &[f64] = &[0.0, -1.0, 0.83862787, 1.0, 0.99339927, 0.94491118];
+
+    test_indicator! {
+        new: RollingCorrelation::new(PERIOD),
+        inputs: INPUTS.iter().copied(),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: RollingCorrelation::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: RollingCorrelation = Default::default();
+    }
+
+    #[test]
+    fn flat_after_random() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut correlation = RollingCorrelation::new(PERIOD)?;
+
+        // An offset leaves rounding residue in the incremental sums
+        for x in RANDOM_DATA.iter() {
+            let _ = correlation.next((x.high() + 1e4, x.low() + 1e4));
+        }
+        // Only `x` goes flat
+        for x in RANDOM_DATA.iter().take(PERIOD) {
+            let _ = correlation.next((1e4 + 0.1, x.low() + 1e4));
+        }
+        assert_eq!(correlation.current(), Some(0.0));
+
+        Ok(())
+    }
+}
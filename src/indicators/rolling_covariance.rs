@@ -0,0 +1,322 @@
+use crate::{
+    Current, Indicator, InvalidRangeError, Lookback, Next, Parameter, Range, Reset, Result, Warmup,
+    WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Rolling Covariance
+///
+/// Population covariance of the last `period` pairs of `(x, y)`.
+/// The co-moments are updated from the deviations of the running means,
+/// so they do not suffer from the cancellation of raw sums of products,
+/// and are recomputed from the ring every `period` pairs so rounding errors cannot pile up.
+/// A series whose last `period` values are identical has exactly that value as its mean
+/// and 0.0 as its (co-)deviations.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingCovariance {
+    period: usize,
+    ring: VecDeque<(f64, f64)>,
+    moments: Option<CoMoments>,
+    since_recompute: usize,
+    repeats_x: usize,
+    repeats_y: usize,
+    count: usize,
+}
+
+/// Running means and sums of (co-)deviations
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct CoMoments {
+    pub(crate) mean_x: f64,
+    pub(crate) mean_y: f64,
+    pub(crate) sxx: f64,
+    pub(crate) syy: f64,
+    pub(crate) sxy: f64,
+}
+
+impl RollingCovariance {
+    pub const DEFAULT_PERIOD: usize = 20;
+
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                period,
+                ring: VecDeque::with_capacity(period),
+                moments: None,
+                since_recompute: 0,
+                repeats_x: 0,
+                repeats_y: 0,
+                count: 0,
+            })
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    pub(crate) fn moments(&self) -> Option<CoMoments> {
+        self.moments
+    }
+
+    fn _next(&mut self, x: f64, y: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        match &mut self.moments {
+            Some(m) => {
+                let (prev_x, prev_y) = *self.ring.back().unwrap();
+                let (old_x, old_y) = self.ring.pop_front().unwrap();
+                self.ring.push_back((x, y));
+
+                self.repeats_x = if x == prev_x { self.repeats_x + 1 } else { 1 };
+                self.repeats_y = if y == prev_y { self.repeats_y + 1 } else { 1 };
+                self.since_recompute += 1;
+
+                if self.period <= self.since_recompute {
+                    self.since_recompute = 0;
+                    *m = Self::recompute(&self.ring);
+                } else {
+                    let n = self.period as f64;
+                    let old_mean_x = m.mean_x;
+                    let old_mean_y = m.mean_y;
+                    m.mean_x += (x - old_x) / n;
+                    m.mean_y += (y - old_y) / n;
+
+                    m.sxx += (x - old_mean_x) * (x - m.mean_x)
+                        - (old_x - old_mean_x) * (old_x - m.mean_x);
+                    m.syy += (y - old_mean_y) * (y - m.mean_y)
+                        - (old_y - old_mean_y) * (old_y - m.mean_y);
+                    m.sxy += (x - old_mean_x) * (y - m.mean_y)
+                        - (old_x - old_mean_x) * (old_y - m.mean_y);
+                }
+
+                if self.period <= self.repeats_x {
+                    m.mean_x = x;
+                    m.sxx = 0.0;
+                    m.sxy = 0.0;
+                }
+                if self.period <= self.repeats_y {
+                    m.mean_y = y;
+                    m.syy = 0.0;
+                    m.sxy = 0.0;
+                }
+            }
+            None => {
+                for _ in 0..self.period {
+                    self.ring.push_back((x, y));
+                }
+                self.moments = CoMoments {
+                    mean_x: x,
+                    mean_y: y,
+                    sxx: 0.0,
+                    syy: 0.0,
+                    sxy: 0.0,
+                }
+                .into();
+                self.repeats_x = self.period;
+                self.repeats_y = self.period;
+            }
+        }
+        self.current().unwrap()
+    }
+
+    /// Two-pass means and sums of (co-)deviations, taken relative to the oldest pair
+    fn recompute(ring: &VecDeque<(f64, f64)>) -> CoMoments {
+        let (shift_x, shift_y) = *ring.front().unwrap();
+        let n = ring.len() as f64;
+        let mean_x = shift_x + ring.iter().map(|(x, _)| x - shift_x).sum::<f64>() / n;
+        let mean_y = shift_y + ring.iter().map(|(_, y)| y - shift_y).sum::<f64>() / n;
+        ring.iter().fold(
+            CoMoments {
+                mean_x,
+                mean_y,
+                sxx: 0.0,
+                syy: 0.0,
+                sxy: 0.0,
+            },
+            |m, (x, y)| CoMoments {
+                sxx: m.sxx + (x - mean_x) * (x - mean_x),
+                syy: m.syy + (y - mean_y) * (y - mean_y),
+                sxy: m.sxy + (x - mean_x) * (y - mean_y),
+                ..m
+            },
+        )
+    }
+}
+impl Default for RollingCovariance {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for RollingCovariance {
+    type Output = f64;
+}
+impl Current for RollingCovariance {
+    fn current(&self) -> Option<Self::Output> {
+        self.moments.map(|m| m.sxy / self.period as f64)
+    }
+}
+impl Next<(f64, f64)> for RollingCovariance {
+    fn next(&mut self, (x, y): (f64, f64)) -> Self::Output {
+        self._next(x, y)
+    }
+}
+impl Reset for RollingCovariance {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ring.clear();
+        self.moments = None;
+        self.since_recompute = 0;
+        self.repeats_x = 0;
+        self.repeats_y = 0;
+    }
+}
+impl WarmupPeriod for RollingCovariance {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl Lookback for RollingCovariance {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helper::*, High, Low};
+
+    const PERIOD: usize = 3;
+    static INPUTS: &[(f64, f64)] = &[
+        (1.0, 2.0),
+        (2.0, 1.0),
+        (4.0, 5.0),
+        (3.0, 3.0),
+        (5.0, 8.0),
+        (4.0, 4.0),
+    ];
+    static OUTPUTS: &[f64] = &[
+        0.0,
+        -0.22222222,
+        1.77777778,
+        1.33333333,
+        1.66666667,
+        1.66666667,
+    ];
+
+    test_indicator! {
+        new: RollingCovariance::new(PERIOD),
+        inputs: INPUTS.iter().copied(),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: RollingCovariance::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: RollingCovariance = Default::default();
+    }
+
+    #[test]
+    fn compare_with_naive() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut covariance = RollingCovariance::new(PERIOD)?;
+        let mut ring = VecDeque::new();
+
+        // Large offsets make raw sums of products lose most of their precision
+        for x in RANDOM_DATA.iter() {
+            let (x, y) = (x.high() + 1e6, x.low() - 1e6);
+            if ring.is_empty() {
+                ring.extend([(x, y); PERIOD]);
+            } else {
+                ring.pop_front();
+                ring.push_back((x, y));
+            }
+
+            let n = PERIOD as f64;
+            let mean_x = ring.iter().map(|(x, _)| x).sum::<f64>() / n;
+            let mean_y = ring.iter().map(|(_, y)| y).sum::<f64>() / n;
+            let correct = ring
+                .iter()
+                .map(|(x, y)| (x - mean_x) * (y - mean_y))
+                .sum::<f64>()
+                / n;
+
+            assert!((covariance.next((x, y)) - correct).abs() < PRECISION * 1e3);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_run_drift() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        const UPDATES: usize = 2_000_000;
+        let mut covariance = RollingCovariance::new(PERIOD)?;
+
+        let inputs: Vec<(f64, f64)> = RANDOM_DATA
+            .iter()
+            .map(|x| (x.high() + 1e6, x.low() - 1e6))
+            .cycle()
+            .take(UPDATES)
+            .collect();
+        for input in inputs.iter() {
+            let _ = covariance.next(*input);
+        }
+
+        let window: VecDeque<(f64, f64)> = inputs[UPDATES - PERIOD..].iter().copied().collect();
+        let correct = RollingCovariance::recompute(&window).sxy / PERIOD as f64;
+        assert!((covariance.current().unwrap() - correct).abs() < 1e-8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flat_after_random() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut covariance = RollingCovariance::new(PERIOD)?;
+
+        for x in RANDOM_DATA.iter() {
+            let _ = covariance.next((x.high(), x.low()));
+        }
+        // Only `y` goes flat
+        for x in RANDOM_DATA.iter().take(PERIOD) {
+            let _ = covariance.next((x.high(), 0.1));
+        }
+
+        let m = covariance.moments().unwrap();
+        assert_eq!(m.mean_y, 0.1);
+        assert_eq!(m.syy, 0.0);
+        assert_eq!(m.sxy, 0.0);
+        assert_eq!(covariance.current(), Some(0.0));
+
+        for _ in 0..PERIOD {
+            let _ = covariance.next((0.2, 0.1));
+        }
+
+        let m = covariance.moments().unwrap();
+        assert_eq!((m.mean_x, m.sxx), (0.2, 0.0));
+
+        Ok(())
+    }
+}
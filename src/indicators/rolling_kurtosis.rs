@@ -0,0 +1,231 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    collections::MomentWindow, Current, Indicator, InvalidRangeError, Lookback, Next, Parameter,
+    Price, Range, Reset, Result, Warmup, WarmupPeriod,
+};
+
+/// Rolling Kurtosis
+///
+/// Population kurtosis of the last `period` inputs, both raw and in excess of the normal distribution's 3.0.
+/// Skewness and both kurtoses are 0.0 while the window is flat.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingKurtosis {
+    window: MomentWindow,
+    count: usize,
+    current: Option<RollingKurtosisOutput>,
+}
+impl RollingKurtosis {
+    pub const DEFAULT_PERIOD: usize = 20;
+
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                window: MomentWindow::new(period),
+                count: 0,
+                current: None,
+            })
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        self.window.push(input);
+        let moments = self.window.moments();
+        let sd = moments.m2.sqrt();
+        let (skewness, kurtosis, excess_kurtosis) = if sd == 0.0 {
+            (0.0, 0.0, 0.0)
+        } else {
+            let kurtosis = moments.m4 / (moments.m2 * moments.m2);
+            (moments.m3 / (moments.m2 * sd), kurtosis, kurtosis - 3.0)
+        };
+
+        self.current = RollingKurtosisOutput {
+            mean: moments.mean,
+            sd,
+            skewness,
+            kurtosis,
+            excess_kurtosis,
+        }
+        .into();
+        self.current().unwrap()
+    }
+}
+impl Default for RollingKurtosis {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for RollingKurtosis {
+    type Output = RollingKurtosisOutput;
+}
+impl Current for RollingKurtosis {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for RollingKurtosis {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for RollingKurtosis {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for RollingKurtosis {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.window.clear();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for RollingKurtosis {
+    fn warmup_period(&self) -> usize {
+        self.window.period() - 1
+    }
+}
+impl Lookback for RollingKurtosis {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingKurtosisOutput {
+    pub mean: f64,
+    pub sd: f64,
+    pub skewness: f64,
+    pub kurtosis: f64,
+    pub excess_kurtosis: f64,
+}
+impl From<(f64, f64, f64, f64, f64)> for RollingKurtosisOutput {
+    fn from((mean, sd, skewness, kurtosis, excess_kurtosis): (f64, f64, f64, f64, f64)) -> Self {
+        Self {
+            mean,
+            sd,
+            skewness,
+            kurtosis,
+            excess_kurtosis,
+        }
+    }
+}
+impl From<RollingKurtosisOutput> for (f64, f64, f64, f64, f64) {
+    fn from(x: RollingKurtosisOutput) -> Self {
+        (x.mean, x.sd, x.skewness, x.kurtosis, x.excess_kurtosis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl Round for RollingKurtosisOutput {
+        fn round(self) -> Self {
+            Self {
+                mean: Round::round(self.mean),
+                sd: Round::round(self.sd),
+                skewness: Round::round(self.skewness),
+                kurtosis: Round::round(self.kurtosis),
+                excess_kurtosis: Round::round(self.excess_kurtosis),
+            }
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 110.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[RollingKurtosisOutput]>> = SyncLazy::new(|| {
+        [
+            (100.0, 0.0, 0.0, 0.0, 0.0),
+            (100.5, 0.8660254, 1.15470054, 2.33333333, -0.66666667),
+            (100.75, 0.8291562, 0.4933822, 1.62809917, -1.37190083),
+            (101.75, 1.47901995, 0.43465076, 1.84571429, -1.15428571),
+            (102.5, 1.11803399, 0.0, 1.64, -1.36),
+            (104.5, 3.35410197, 0.79504639, 2.11407407, -0.88592593),
+            (105.5, 2.6925824, 0.9220734, 2.16052319, -0.83947681),
+        ]
+        .into_iter()
+        .map(RollingKurtosisOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: RollingKurtosis::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: RollingKurtosis::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: RollingKurtosis = Default::default();
+    }
+
+    #[test]
+    fn flat_after_random() -> crate::Result<()> {
+        let mut indicator = RollingKurtosis::new(PERIOD)?;
+
+        for x in RANDOM_DATA.iter() {
+            let _ = indicator.next(x.price() + 900.0);
+        }
+        let mut output = indicator.next(1000.0);
+        for _ in 1..PERIOD {
+            output = indicator.next(1000.0);
+        }
+
+        assert_eq!(output.sd, 0.0);
+        assert_eq!(output.skewness, 0.0);
+        assert_eq!(output.excess_kurtosis, 0.0);
+
+        Ok(())
+    }
+}
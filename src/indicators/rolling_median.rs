@@ -0,0 +1,146 @@
+use crate::{
+    Current, Indicator, Lookback, Next, Price, Reset, Result, RollingQuantile, Warmup, WarmupPeriod,
+};
+
+/// Rolling Median
+///
+/// Median of the last `period` inputs, averaging the two middle values when `period` is even.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingMedian {
+    quantile: RollingQuantile,
+}
+impl RollingMedian {
+    pub const DEFAULT_PERIOD: usize = RollingQuantile::DEFAULT_PERIOD;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let quantile = RollingQuantile::new(period, 0.5)?;
+        Ok(Self { quantile })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.quantile.next(input)
+    }
+}
+impl Default for RollingMedian {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for RollingMedian {
+    type Output = f64;
+}
+impl Current for RollingMedian {
+    fn current(&self) -> Option<Self::Output> {
+        self.quantile.current()
+    }
+}
+impl Next<f64> for RollingMedian {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for RollingMedian {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for RollingMedian {
+    fn reset(&mut self) {
+        self.quantile.reset();
+    }
+}
+impl WarmupPeriod for RollingMedian {
+    fn warmup_period(&self) -> usize {
+        self.quantile.warmup_period()
+    }
+}
+impl Lookback for RollingMedian {
+    fn is_ready(&self) -> bool {
+        self.quantile.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+    use alloc::collections::VecDeque;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 110.0, 105.0, 99.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[100.0, 100.0, 100.5, 101.5, 102.5, 103.5, 104.5, 104.0];
+
+    test_indicator! {
+        new: RollingMedian::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: RollingMedian::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: RollingMedian = Default::default();
+    }
+
+    #[test]
+    fn compare_with_naive() -> crate::Result<()> {
+        const PERIOD: usize = 15;
+        let mut median = RollingMedian::new(PERIOD)?;
+        let mut ring = VecDeque::new();
+
+        for x in RANDOM_DATA.iter() {
+            let price = x.price();
+            if ring.is_empty() {
+                ring.extend([price; PERIOD]);
+            } else {
+                ring.pop_front();
+                ring.push_back(price);
+            }
+            let mut sorted: Vec<f64> = ring.iter().copied().collect();
+            sorted.sort_by(f64::total_cmp);
+
+            assert_eq!(median.next(x), sorted[PERIOD / 2]);
+        }
+
+        Ok(())
+    }
+}
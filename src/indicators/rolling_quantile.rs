@@ -0,0 +1,211 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    collections::OrderStatisticTree, Current, Indicator, InvalidRangeError, Lookback, Next,
+    Parameter, Price, Range, Reset, Result, Warmup, WarmupPeriod,
+};
+use alloc::collections::VecDeque;
+
+/// Rolling Quantile
+///
+/// `q`-quantile of the last `period` inputs, linearly interpolated between
+/// the two nearest order statistics. Updated in O(log n) per bar.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingQuantile {
+    period: usize,
+    q: f64,
+    ring: VecDeque<f64>,
+    sorted: OrderStatisticTree,
+    count: usize,
+    current: Option<f64>,
+}
+impl RollingQuantile {
+    pub const DEFAULT_PERIOD: usize = 20;
+
+    pub fn new(period: usize, q: f64) -> Result<Self> {
+        if period < 1 {
+            return Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into());
+        }
+        if !(0.0..=1.0).contains(&q) {
+            return Err(InvalidRangeError {
+                param: Parameter::new("q", q),
+                range: Range::BothBounded { min: 0.0, max: 1.0 },
+            }
+            .into());
+        }
+
+        Ok(Self {
+            period,
+            q,
+            ring: VecDeque::with_capacity(period),
+            sorted: OrderStatisticTree::with_capacity(period),
+            count: 0,
+            current: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, q: f64, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period, q).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        if self.ring.is_empty() {
+            for _ in 0..self.period {
+                self.ring.push_back(input);
+                self.sorted.insert(input);
+            }
+        } else {
+            let old_val = self.ring.pop_front().unwrap();
+            let _ = self.sorted.remove(old_val);
+            self.ring.push_back(input);
+            self.sorted.insert(input);
+        }
+
+        let h = (self.period - 1) as f64 * self.q;
+        let lower_index = h.floor() as usize;
+        let lower = self.sorted.get(lower_index).unwrap();
+        let quantile = match self.sorted.get(lower_index + 1) {
+            Some(upper) => lower + (h - lower_index as f64) * (upper - lower),
+            None => lower,
+        };
+        self.current = quantile.into();
+        quantile
+    }
+}
+
+impl Indicator for RollingQuantile {
+    type Output = f64;
+}
+impl Current for RollingQuantile {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for RollingQuantile {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for RollingQuantile {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for RollingQuantile {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ring.clear();
+        self.sorted.clear();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for RollingQuantile {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl Lookback for RollingQuantile {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 4;
+    const Q: f64 = 0.3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 110.0, 105.0, 99.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[100.0, 100.0, 100.0, 100.9, 101.9, 102.8, 103.9, 102.6];
+
+    test_indicator! {
+        new: RollingQuantile::new(PERIOD, Q),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    RollingQuantile::new(0, Q),
+                    RollingQuantile::new(PERIOD, -0.1),
+                    RollingQuantile::new(PERIOD, 1.1),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn extremes() -> crate::Result<()> {
+        use crate::{Max, Min};
+
+        let mut min = Min::new(PERIOD)?;
+        let mut max = Max::new(PERIOD)?;
+        let mut q0 = RollingQuantile::new(PERIOD, 0.0)?;
+        let mut q1 = RollingQuantile::new(PERIOD, 1.0)?;
+
+        for x in RANDOM_DATA.iter() {
+            assert_eq!(q0.next(x), min.next(x));
+            assert_eq!(q1.next(x), max.next(x));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn nan_ages_out() -> crate::Result<()> {
+        const PERIOD: usize = 5;
+        let mut max = RollingQuantile::new(PERIOD, 1.0)?;
+        let mut ring = VecDeque::from([f64::NAN; PERIOD]);
+
+        let _ = max.next(f64::NAN);
+        for x in RANDOM_DATA.iter().take(100) {
+            let price = x.price();
+            ring.pop_front();
+            ring.push_back(price);
+            let output = max.next(price);
+
+            if !ring.iter().any(|x| x.is_nan()) {
+                assert_eq!(output, ring.iter().copied().fold(f64::MIN, f64::max));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    collections::MomentWindow, Current, Indicator, InvalidRangeError, Lookback, Next, Parameter,
+    Price, Range, Reset, Result, Warmup, WarmupPeriod,
+};
+
+/// Rolling Skewness
+///
+/// Population skewness of the last `period` inputs, or 0.0 while the window is flat.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingSkewness {
+    window: MomentWindow,
+    count: usize,
+    current: Option<RollingSkewnessOutput>,
+}
+impl RollingSkewness {
+    pub const DEFAULT_PERIOD: usize = 20;
+
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                window: MomentWindow::new(period),
+                count: 0,
+                current: None,
+            })
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        self.window.push(input);
+        let moments = self.window.moments();
+        let sd = moments.m2.sqrt();
+        let skewness = if sd == 0.0 {
+            0.0
+        } else {
+            moments.m3 / (moments.m2 * sd)
+        };
+
+        self.current = RollingSkewnessOutput {
+            mean: moments.mean,
+            sd,
+            skewness,
+        }
+        .into();
+        self.current().unwrap()
+    }
+}
+impl Default for RollingSkewness {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for RollingSkewness {
+    type Output = RollingSkewnessOutput;
+}
+impl Current for RollingSkewness {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for RollingSkewness {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for RollingSkewness {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for RollingSkewness {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.window.clear();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for RollingSkewness {
+    fn warmup_period(&self) -> usize {
+        self.window.period() - 1
+    }
+}
+impl Lookback for RollingSkewness {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingSkewnessOutput {
+    pub mean: f64,
+    pub sd: f64,
+    pub skewness: f64,
+}
+impl From<(f64, f64, f64)> for RollingSkewnessOutput {
+    fn from((mean, sd, skewness): (f64, f64, f64)) -> Self {
+        Self { mean, sd, skewness }
+    }
+}
+impl From<RollingSkewnessOutput> for (f64, f64, f64) {
+    fn from(x: RollingSkewnessOutput) -> Self {
+        (x.mean, x.sd, x.skewness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl Round for RollingSkewnessOutput {
+        fn round(self) -> Self {
+            Self {
+                mean: Round::round(self.mean),
+                sd: Round::round(self.sd),
+                skewness: Round::round(self.skewness),
+            }
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 110.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[RollingSkewnessOutput]>> = SyncLazy::new(|| {
+        [
+            (100.0, 0.0, 0.0),
+            (100.5, 0.8660254, 1.15470054),
+            (100.75, 0.8291562, 0.4933822),
+            (101.75, 1.47901995, 0.43465076),
+            (102.5, 1.11803399, 0.0),
+            (104.5, 3.35410197, 0.79504639),
+            (105.5, 2.6925824, 0.9220734),
+        ]
+        .into_iter()
+        .map(RollingSkewnessOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: RollingSkewness::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: RollingSkewness::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: RollingSkewness = Default::default();
+    }
+
+    #[test]
+    fn flat_after_random() -> crate::Result<()> {
+        let mut indicator = RollingSkewness::new(PERIOD)?;
+
+        for x in RANDOM_DATA.iter() {
+            let _ = indicator.next(x.price() + 900.0);
+        }
+        let mut output = indicator.next(1000.0);
+        for _ in 1..PERIOD {
+            output = indicator.next(1000.0);
+        }
+
+        assert_eq!(output.sd, 0.0);
+        assert_eq!(output.skewness, 0.0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    collections::MomentWindow, Current, Indicator, InvalidRangeError, Lookback, Next, Parameter,
+    Price, Range, Reset, Result, Warmup, WarmupPeriod,
+};
+
+/// Rolling Z-Score
+///
+/// Distance of the input from the mean of the last `period` inputs,
+/// in units of their (population) standard deviation.
+/// Outputs a z-score of 0.0 while the window is flat.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingZScore {
+    window: MomentWindow,
+    count: usize,
+    current: Option<RollingZScoreOutput>,
+}
+impl RollingZScore {
+    pub const DEFAULT_PERIOD: usize = 20;
+
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            Ok(Self {
+                window: MomentWindow::new(period),
+                count: 0,
+                current: None,
+            })
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        self.window.push(input);
+        let moments = self.window.moments();
+        let sd = moments.m2.sqrt();
+        let z_score = if sd == 0.0 {
+            0.0
+        } else {
+            (input - moments.mean) / sd
+        };
+
+        self.current = RollingZScoreOutput {
+            mean: moments.mean,
+            sd,
+            z_score,
+        }
+        .into();
+        self.current().unwrap()
+    }
+}
+impl Default for RollingZScore {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for RollingZScore {
+    type Output = RollingZScoreOutput;
+}
+impl Current for RollingZScore {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for RollingZScore {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for RollingZScore {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for RollingZScore {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.window.clear();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for RollingZScore {
+    fn warmup_period(&self) -> usize {
+        self.window.period() - 1
+    }
+}
+impl Lookback for RollingZScore {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingZScoreOutput {
+    pub mean: f64,
+    pub sd: f64,
+    pub z_score: f64,
+}
+impl From<(f64, f64, f64)> for RollingZScoreOutput {
+    fn from((mean, sd, z_score): (f64, f64, f64)) -> Self {
+        Self { mean, sd, z_score }
+    }
+}
+impl From<RollingZScoreOutput> for (f64, f64, f64) {
+    fn from(x: RollingZScoreOutput) -> Self {
+        (x.mean, x.sd, x.z_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helper::*, IndicatorExt, Rsi};
+    use once_cell::sync::Lazy as SyncLazy;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    impl Round for RollingZScoreOutput {
+        fn round(self) -> Self {
+            Self {
+                mean: Round::round(self.mean),
+                sd: Round::round(self.sd),
+                z_score: Round::round(self.z_score),
+            }
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 110.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[RollingZScoreOutput]>> = SyncLazy::new(|| {
+        [
+            (100.0, 0.0, 0.0),
+            (100.5, 0.8660254, 1.73205081),
+            (100.75, 0.8291562, 0.30151134),
+            (101.75, 1.47901995, 1.52127766),
+            (102.5, 1.11803399, 0.4472136),
+            (104.5, 3.35410197, 1.63978318),
+            (105.5, 2.6925824, -0.18569534),
+        ]
+        .into_iter()
+        .map(RollingZScoreOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: RollingZScore::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: RollingZScore::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: RollingZScore = Default::default();
+    }
+
+    #[test]
+    fn pullback() -> crate::Result<()> {
+        let mut rsi = Rsi::new(PERIOD)?;
+        let mut z_score = RollingZScore::new(PERIOD)?;
+        let mut rsi_z_score = Rsi::new(PERIOD)?.pullback(RollingZScore::new(PERIOD)?);
+
+        for x in RANDOM_DATA.iter() {
+            assert_eq!(rsi_z_score.next(x), z_score.next(rsi.next(x)));
+        }
+
+        Ok(())
+    }
+}
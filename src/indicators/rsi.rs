@@ -1,28 +1,44 @@
-use crate::{Current, Indicator, Next, Price, Reset, Result, Rma};
+use crate::{
+    Current, Indicator, Lookback, Next, Price, Reset, Result, Rma, Seed, Warmup, WarmupPeriod,
+};
 
 /// Relative Strength Index
 ///
 /// Range in \[0.0, 1.0\]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rsi {
     up: Rma,
     down: Rma,
+    seed: Seed,
     prev_input: Option<f64>,
+    count: usize,
 }
 impl Rsi {
     pub const DEFAULT_PERIOD: usize = 14;
 
     pub fn new(period: usize) -> Result<Self> {
-        let up = Rma::new(period)?;
-        let down = Rma::new(period)?;
+        Self::with_seed(period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(period: usize, seed: Seed) -> Result<Self> {
+        let up = Rma::with_seed(period, seed)?;
+        let down = Rma::with_seed(period, seed)?;
         Ok(Self {
             up,
             down,
+            seed,
             prev_input: None,
+            count: 0,
         })
     }
 
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         match &mut self.prev_input {
             Some(prev_input) => {
                 let change = input - *prev_input;
@@ -31,8 +47,11 @@ impl Rsi {
                 *prev_input = input;
             }
             None => {
-                let _ = self.up.next(0.0);
-                let _ = self.down.next(0.0);
+                // A mean seed averages the first `period` changes only
+                if self.seed == Seed::First {
+                    let _ = self.up.next(0.0);
+                    let _ = self.down.next(0.0);
+                }
                 self.prev_input = input.into();
             }
         }
@@ -42,11 +61,7 @@ impl Rsi {
 }
 impl Default for Rsi {
     fn default() -> Self {
-        Self {
-            up: Rma::new(Self::DEFAULT_PERIOD).unwrap(),
-            down: Rma::new(Self::DEFAULT_PERIOD).unwrap(),
-            prev_input: None,
-        }
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
     }
 }
 
@@ -63,7 +78,8 @@ impl Current for Rsi {
                 (up, down) => 1.0 / (1.0 + down / up),
             }
             .into(),
-            _ => None,
+            // No change has been seen yet
+            _ => self.prev_input.map(|_| 0.5),
         }
     }
 }
@@ -79,18 +95,30 @@ impl<Input: Price> Next<&Input> for Rsi {
 }
 impl Reset for Rsi {
     fn reset(&mut self) {
+        self.count = 0;
         self.up.reset();
         self.down.reset();
         self.prev_input = None;
     }
 }
+impl WarmupPeriod for Rsi {
+    fn warmup_period(&self) -> usize {
+        // The first input has no change
+        self.up.warmup_period() + 1
+    }
+}
+impl Lookback for Rsi {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use once_cell::sync::Lazy as SyncLazy;
 
     use super::*;
-    use crate::test_helper::*;
+    use crate::{test_helper::*, SmaSeed};
 
     #[derive(Clone)]
     struct TestItem(f64);
@@ -128,6 +156,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
 
@@ -135,4 +166,30 @@ mod tests {
     fn default() {
         let _: Rsi = Default::default();
     }
+
+    #[test]
+    fn sma_seed() -> crate::Result<()> {
+        const PERIOD: usize = 14;
+        let mut rsi = Rsi::with_warmup(PERIOD, SmaSeed)?;
+
+        // Wilder's RSI as TA-Lib computes it, which starts from the averages of the first `PERIOD` changes
+        let inputs: Vec<f64> = RANDOM_DATA.iter().map(|x| x.price()).collect();
+        let changes: Vec<f64> = inputs.windows(2).map(|w| w[1] - w[0]).collect();
+        let mut up = changes[..PERIOD].iter().map(|x| x.max(0.0)).sum::<f64>() / PERIOD as f64;
+        let mut down = changes[..PERIOD].iter().map(|x| (-x).max(0.0)).sum::<f64>() / PERIOD as f64;
+        for (i, x) in inputs.iter().enumerate() {
+            let value = rsi.next(*x);
+            if PERIOD < i {
+                let change = changes[i - 1];
+                up = (up * (PERIOD - 1) as f64 + change.max(0.0)) / PERIOD as f64;
+                down = (down * (PERIOD - 1) as f64 + (-change).max(0.0)) / PERIOD as f64;
+            }
+            if PERIOD <= i {
+                assert!((value - up / (up + down)).abs() < PRECISION);
+            }
+        }
+        assert_eq!(rsi.warmup_period(), PERIOD);
+
+        Ok(())
+    }
 }
@@ -1,15 +1,47 @@
-use crate::{Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result};
-use alloc::collections::VecDeque;
+#[cfg(feature = "alloc")]
+use crate::Warmup;
+use crate::{
+    collections::NeumaierSum, storage::check_capacity, Current, DefaultStorage, Indicator, Inline,
+    InvalidRangeError, Lookback, Next, Number, Parameter, Price, Range, Reset, Result, Ring,
+    Storage, WarmupPeriod,
+};
 
 /// Simple Moving Average
+///
+/// The running sum is compensated, so it does not drift on long-running feeds.
 #[derive(Debug, Clone)]
-pub struct Sma {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, S::Ring<T>: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, S::Ring<T>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Sma<T: Number = f64, S: Storage = DefaultStorage> {
     period: usize,
-    ring: VecDeque<f64>,
-    sum: Option<f64>,
+    ring: S::Ring<T>,
+    sum: Option<NeumaierSum<T>>,
+    count: usize,
 }
+
+/// [`Sma`] over a period of `P` fixed at compile time, kept inline without allocating
+///
+/// Built by `SmaN::<P>::default()`, and outputs exactly what `Sma::new(P)` does.
+pub type SmaN<const P: usize, T = f64> = Sma<T, Inline<P>>;
+#[cfg(feature = "alloc")]
 impl Sma {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+}
+impl<T: Number, S: Storage> Sma<T, S> {
+    /// Same as `new`, in any [`Number`] type and [`Storage`]
+    pub fn with_period(period: usize) -> Result<Self> {
         if period < 1 {
             Err(InvalidRangeError {
                 param: Parameter::new("period", period),
@@ -17,56 +49,72 @@ impl Sma {
             }
             .into())
         } else {
+            check_capacity::<S>(period, period)?;
             Ok(Self {
                 period,
-                ring: VecDeque::with_capacity(period),
+                ring: Ring::with_capacity(period),
                 sum: None,
+                count: 0,
             })
         }
     }
 
-    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+    fn _next(&mut self, input: T) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         match &mut self.sum {
             Some(sum) => {
-                *sum -= self.ring.pop_front().unwrap();
+                sum.sub(self.ring.pop_front().unwrap());
                 self.ring.push_back(input);
-                *sum += input;
+                sum.add(input);
             }
             None => {
                 for _ in 0..self.period {
                     self.ring.push_back(input);
                 }
-                self.sum = (input * self.period as f64).into();
+                self.sum = NeumaierSum::new(input * T::from_usize(self.period)).into();
             }
         }
         self.current().unwrap()
     }
 }
+impl<T: Number, const P: usize> Default for Sma<T, Inline<P>> {
+    fn default() -> Self {
+        const { assert!(0 < P, "period must be at least 1") };
+        Self::with_period(P).unwrap()
+    }
+}
 
-impl Indicator for Sma {
-    type Output = f64;
+impl<T: Number, S: Storage> Indicator for Sma<T, S> {
+    type Output = T;
 }
-impl Current for Sma {
+impl<T: Number, S: Storage> Current for Sma<T, S> {
     fn current(&self) -> Option<Self::Output> {
-        self.sum.map(|s| s / self.period as f64)
+        self.sum.map(|s| s.value() / T::from_usize(self.period))
     }
 }
-impl Next<f64> for Sma {
-    fn next(&mut self, input: f64) -> Self::Output {
-        self._next(input)
-    }
-}
-impl<Input: Price> Next<&Input> for Sma {
+impl_next_for_numbers!(storage Sma);
+impl<T: Number, S: Storage, Input: Price<T>> Next<&Input> for Sma<T, S> {
     fn next(&mut self, input: &Input) -> Self::Output {
         self._next(input.price())
     }
 }
-impl Reset for Sma {
+impl<T: Number, S: Storage> Reset for Sma<T, S> {
     fn reset(&mut self) {
+        self.count = 0;
         self.ring.clear();
         self.sum = None;
     }
 }
+impl<T: Number, S: Storage> WarmupPeriod for Sma<T, S> {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl<T: Number, S: Storage> Lookback for Sma<T, S> {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -111,6 +159,46 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
+
+    #[test]
+    fn long_run_drift() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        const UPDATES: usize = 2_000_000;
+        let mut sma = Sma::new(PERIOD)?;
+
+        // A large offset with small fluctuations is where an uncompensated sum drifts the most
+        let inputs: Vec<f64> = RANDOM_DATA
+            .iter()
+            .map(|x| 1e6 + x.price())
+            .cycle()
+            .take(UPDATES)
+            .collect();
+        for input in inputs.iter() {
+            let _ = sma.next(*input);
+        }
+
+        let window = &inputs[UPDATES - PERIOD..];
+        let correct = window.iter().sum::<f64>() / PERIOD as f64;
+        assert!((sma.current().unwrap() - correct).abs() < 1e-8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn const_period() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut sma = Sma::new(PERIOD)?;
+        let mut inline = SmaN::<PERIOD>::default();
+
+        for x in RANDOM_DATA.iter() {
+            assert_eq!(inline.next(x), sma.next(x));
+        }
+
+        Ok(())
+    }
 }
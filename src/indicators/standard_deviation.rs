@@ -1,22 +1,62 @@
-use crate::{Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result};
-use alloc::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+#[cfg(feature = "alloc")]
+use crate::Warmup;
+use crate::{
+    storage::check_capacity, Current, DefaultStorage, Indicator, Inline, InvalidRangeError,
+    Lookback, Next, Parameter, Price, Range, Reset, Result, Ring, Storage, WarmupPeriod,
+};
 
 /// Standard Deviation
+///
+/// The mean and the sum of squared errors are updated incrementally and recomputed
+/// from the ring every `period` bars, so rounding errors cannot pile up on long-running feeds.
+/// A window of identical inputs yields exactly that input as the mean and 0.0 as the sd.
 #[derive(Debug, Clone)]
-pub struct StandardDeviation {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S::Ring<f64>: serde::Serialize",
+        deserialize = "S::Ring<f64>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct StandardDeviation<S: Storage = DefaultStorage> {
     period: usize,
-    ring: VecDeque<f64>,
+    ring: S::Ring<f64>,
     mean_sse: Option<(f64, f64)>,
+    since_recompute: usize,
+    repeats: usize,
+    count: usize,
 }
 
+/// [`StandardDeviation`] over a period of `P` fixed at compile time, kept inline without allocating
+///
+/// Built by `StandardDeviationN::<P>::default()`,
+/// and outputs exactly what `StandardDeviation::new(P)` does.
+pub type StandardDeviationN<const P: usize> = StandardDeviation<Inline<P>>;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StandardDeviationOutput {
     pub mean: f64,
     pub sd: f64,
 }
 
+#[cfg(feature = "alloc")]
 impl StandardDeviation {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+}
+impl<S: Storage> StandardDeviation<S> {
+    /// Same as `new`, in any [`Storage`]
+    pub fn with_period(period: usize) -> Result<Self> {
         if period < 1 {
             Err(InvalidRangeError {
                 param: Parameter::new("period", period),
@@ -24,46 +64,82 @@ impl StandardDeviation {
             }
             .into())
         } else {
+            check_capacity::<S>(period, period)?;
             Ok(Self {
                 period,
-                ring: VecDeque::with_capacity(period),
+                ring: Ring::with_capacity(period),
                 mean_sse: None,
+                since_recompute: 0,
+                repeats: 0,
+                count: 0,
             })
         }
     }
 
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         match &mut self.mean_sse {
             Some((mean, sse)) => {
+                let prev_input = *self.ring.back().unwrap();
                 let old_input = self.ring.pop_front().unwrap();
                 self.ring.push_back(input);
 
-                let delta = input - old_input;
-                let old_mean = *mean;
-                *mean += delta / self.period as f64;
-                let delta2 = input - *mean + old_input - old_mean;
-                *sse += delta * delta2;
+                self.repeats = if input == prev_input {
+                    self.repeats + 1
+                } else {
+                    1
+                };
+                self.since_recompute += 1;
+
+                if self.period <= self.repeats {
+                    *mean = input;
+                    *sse = 0.0;
+                } else if self.period <= self.since_recompute {
+                    self.since_recompute = 0;
+                    self.mean_sse = Self::recompute(&self.ring).into();
+                } else {
+                    let delta = input - old_input;
+                    let old_mean = *mean;
+                    *mean += delta / self.period as f64;
+                    let delta2 = input - *mean + old_input - old_mean;
+                    *sse += delta * delta2;
+                }
             }
             None => {
                 for _ in 0..self.period {
                     self.ring.push_back(input);
                 }
                 self.mean_sse = (input, 0.0).into();
+                self.repeats = self.period;
             }
         }
         self.current().unwrap()
     }
+
+    /// Two-pass mean and sum of squared errors, taken relative to the oldest input
+    fn recompute(ring: &S::Ring<f64>) -> (f64, f64) {
+        let shift = *ring.front().unwrap();
+        let mean = shift + ring.iter().map(|x| x - shift).sum::<f64>() / ring.len() as f64;
+        let sse = ring.iter().map(|x| (x - mean) * (x - mean)).sum();
+        (mean, sse)
+    }
+}
+impl<const P: usize> Default for StandardDeviation<Inline<P>> {
+    fn default() -> Self {
+        const { assert!(0 < P, "period must be at least 1") };
+        Self::with_period(P).unwrap()
+    }
 }
 
-impl Indicator for StandardDeviation {
+impl<S: Storage> Indicator for StandardDeviation<S> {
     type Output = StandardDeviationOutput;
 }
-impl Current for StandardDeviation {
+impl<S: Storage> Current for StandardDeviation<S> {
     fn current(&self) -> Option<Self::Output> {
         if let Some((mean, sse)) = self.mean_sse {
             Self::Output {
                 mean,
-                sd: (sse / self.period as f64).sqrt(),
+                sd: (sse.max(0.0) / self.period as f64).sqrt(),
             }
             .into()
         } else {
@@ -71,20 +147,33 @@ impl Current for StandardDeviation {
         }
     }
 }
-impl Next<f64> for StandardDeviation {
+impl<S: Storage> Next<f64> for StandardDeviation<S> {
     fn next(&mut self, input: f64) -> Self::Output {
         self._next(input)
     }
 }
-impl<Input: Price> Next<&Input> for StandardDeviation {
+impl<S: Storage, Input: Price> Next<&Input> for StandardDeviation<S> {
     fn next(&mut self, input: &Input) -> Self::Output {
         self._next(input.price())
     }
 }
-impl Reset for StandardDeviation {
+impl<S: Storage> Reset for StandardDeviation<S> {
     fn reset(&mut self) {
+        self.count = 0;
         self.ring.clear();
         self.mean_sse = None;
+        self.since_recompute = 0;
+        self.repeats = 0;
+    }
+}
+impl<S: Storage> WarmupPeriod for StandardDeviation<S> {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl<S: Storage> Lookback for StandardDeviation<S> {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
     }
 }
 
@@ -150,6 +239,67 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         },
     }
+
+    #[test]
+    fn long_run_drift() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        const UPDATES: usize = 2_000_000;
+        let mut standard_deviation = StandardDeviation::new(PERIOD)?;
+
+        let inputs: Vec<f64> = RANDOM_DATA
+            .iter()
+            .map(|x| 1e6 + x.price())
+            .cycle()
+            .take(UPDATES)
+            .collect();
+        for input in inputs.iter() {
+            let _ = standard_deviation.next(*input);
+        }
+
+        let window = &inputs[UPDATES - PERIOD..];
+        let mean = window.iter().sum::<f64>() / PERIOD as f64;
+        let sd = (window.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / PERIOD as f64).sqrt();
+        let output = standard_deviation.current().unwrap();
+        assert!((output.mean - mean).abs() < 1e-8);
+        assert!((output.sd - sd).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flat() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut standard_deviation = StandardDeviation::new(PERIOD)?;
+
+        for x in RANDOM_DATA.iter() {
+            let _ = standard_deviation.next(x);
+        }
+        for _ in 0..PERIOD {
+            let _ = standard_deviation.next(0.1);
+        }
+
+        let output = standard_deviation.current().unwrap();
+        assert_eq!(output.mean, 0.1);
+        assert_eq!(output.sd, 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn const_period() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut sd = StandardDeviation::new(PERIOD)?;
+        let mut inline = StandardDeviationN::<PERIOD>::default();
+
+        for x in RANDOM_DATA.iter() {
+            assert_eq!(inline.next(x), sd.next(x));
+        }
+
+        Ok(())
+    }
 }
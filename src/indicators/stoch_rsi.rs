@@ -0,0 +1,250 @@
+use crate::{
+    Current, Indicator, Lookback, Max, Min, Next, Price, Reset, Result, Rsi, Seed, Sma, Warmup,
+    WarmupPeriod,
+};
+
+/// Stochastic RSI
+///
+/// The stochastic oscillator applied to [`Rsi`], with `k` smoothing the raw value
+/// and `d` smoothing `k`.
+///
+/// Range in \[0.0, 1.0\]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochRsi {
+    rsi: Rsi,
+    max: Max,
+    min: Min,
+    k: Sma,
+    d: Sma,
+    count: usize,
+    current: Option<StochRsiOutput>,
+}
+impl StochRsi {
+    pub const DEFAULT_RSI_PERIOD: usize = 14;
+    pub const DEFAULT_STOCH_PERIOD: usize = 14;
+    pub const DEFAULT_K_PERIOD: usize = 3;
+    pub const DEFAULT_D_PERIOD: usize = 3;
+
+    pub fn new(
+        rsi_period: usize,
+        stoch_period: usize,
+        k_period: usize,
+        d_period: usize,
+    ) -> Result<Self> {
+        Self::with_seed(rsi_period, stoch_period, k_period, d_period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        rsi_period: usize,
+        stoch_period: usize,
+        k_period: usize,
+        d_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(rsi_period, stoch_period, k_period, d_period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(
+        rsi_period: usize,
+        stoch_period: usize,
+        k_period: usize,
+        d_period: usize,
+        seed: Seed,
+    ) -> Result<Self> {
+        Ok(Self {
+            rsi: Rsi::with_seed(rsi_period, seed)?,
+            max: Max::new(stoch_period)?,
+            min: Min::new(stoch_period)?,
+            k: Sma::new(k_period)?,
+            d: Sma::new(d_period)?,
+            count: 0,
+            current: None,
+        })
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let rsi = self.rsi.next(input);
+        let max = self.max.next(rsi);
+        let min = self.min.next(rsi);
+        let stoch = if max == min {
+            0.5
+        } else {
+            (rsi - min) / (max - min)
+        };
+        let k = self.k.next(stoch);
+        let d = self.d.next(k);
+
+        self.current = StochRsiOutput { k, d }.into();
+        self.current().unwrap()
+    }
+}
+impl Default for StochRsi {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_RSI_PERIOD,
+            Self::DEFAULT_STOCH_PERIOD,
+            Self::DEFAULT_K_PERIOD,
+            Self::DEFAULT_D_PERIOD,
+        )
+        .unwrap()
+    }
+}
+
+impl Indicator for StochRsi {
+    type Output = StochRsiOutput;
+}
+impl Current for StochRsi {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for StochRsi {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for StochRsi {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for StochRsi {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.rsi.reset();
+        self.max.reset();
+        self.min.reset();
+        self.k.reset();
+        self.d.reset();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for StochRsi {
+    fn warmup_period(&self) -> usize {
+        self.rsi.warmup_period()
+            + self.max.warmup_period()
+            + self.k.warmup_period()
+            + self.d.warmup_period()
+    }
+}
+impl Lookback for StochRsi {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochRsiOutput {
+    pub k: f64,
+    pub d: f64,
+}
+impl From<(f64, f64)> for StochRsiOutput {
+    fn from((k, d): (f64, f64)) -> Self {
+        Self { k, d }
+    }
+}
+impl From<StochRsiOutput> for (f64, f64) {
+    fn from(x: StochRsiOutput) -> Self {
+        (x.k, x.d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for StochRsiOutput {
+        fn round(self) -> Self {
+            Self {
+                k: Round::round(self.k),
+                d: Round::round(self.d),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const RSI_PERIOD: usize = 3;
+    const STOCH_PERIOD: usize = 3;
+    const K_PERIOD: usize = 2;
+    const D_PERIOD: usize = 2;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 102.0, 101.0, 100.0, 99.0, 101.0, 103.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[StochRsiOutput]>> = SyncLazy::new(|| {
+        [
+            (0.5, 0.5),
+            (0.75, 0.625),
+            (1.0, 0.875),
+            (0.5, 0.75),
+            (0.0, 0.25),
+            (0.0, 0.0),
+            (0.5, 0.25),
+            (1.0, 0.75),
+        ]
+        .into_iter()
+        .map(StochRsiOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: StochRsi::new(RSI_PERIOD, STOCH_PERIOD, K_PERIOD, D_PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    StochRsi::new(1, 1, 1, 1),
+                    StochRsi::new(2, 0, 1, 1),
+                    StochRsi::new(2, 1, 0, 1),
+                    StochRsi::new(2, 1, 1, 0),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: StochRsi = Default::default();
+    }
+
+    #[test]
+    fn range() {
+        let mut stoch_rsi = StochRsi::default();
+
+        for x in RANDOM_DATA.iter() {
+            let output = stoch_rsi.next(x);
+            assert!((-PRECISION..=1.0 + PRECISION).contains(&output.k));
+            assert!((-PRECISION..=1.0 + PRECISION).contains(&output.d));
+        }
+    }
+}
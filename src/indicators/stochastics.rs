@@ -1,15 +1,21 @@
-use crate::{Current, Indicator, Max, Min, Next, Price, Reset, Result, Sma};
+use crate::{
+    Current, Indicator, Lookback, Max, Min, Next, Price, Reset, Result, Seed, Sma, Warmup,
+    WarmupPeriod,
+};
 
 /// Stochastics
 ///
 /// Range in \[0.0, 1.0\]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Stochastics {
     min: Min,
     max: Max,
     d_numerator: Sma,
     d_denominator: Sma,
     slow_d: Sma,
+    seed: Seed,
+    count: usize,
     current: Option<StochasticsOutput>,
 }
 impl Stochastics {
@@ -17,8 +23,25 @@ impl Stochastics {
     pub const DEFAULT_M_PERIOD: usize = 3;
     pub const DEFAULT_X_PERIOD: usize = 3;
 
-    ///
     pub fn new(n_period: usize, m_period: usize, x_period: usize) -> Result<Self> {
+        Self::with_seed(n_period, m_period, x_period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        n_period: usize,
+        m_period: usize,
+        x_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(n_period, m_period, x_period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(
+        n_period: usize,
+        m_period: usize,
+        x_period: usize,
+        seed: Seed,
+    ) -> Result<Self> {
         let min = Min::new(n_period)?;
         let max = Max::new(n_period)?;
         let d_numerator = Sma::new(m_period)?;
@@ -30,40 +53,45 @@ impl Stochastics {
             d_numerator,
             d_denominator,
             slow_d,
+            seed,
+            count: 0,
             current: None,
         })
     }
 
     fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         let min = self.min.next(input);
         let max = self.max.next(input);
 
-        match &mut self.current {
-            Some(current) => {
+        match self.current {
+            // Replicating the first input also starts the smoothing from 0.0
+            None if self.seed == Seed::First => {
+                let _ = self.d_numerator.next(0.0);
+                let _ = self.d_denominator.next(0.0);
+                let _ = self.slow_d.next(0.0);
+                self.current = StochasticsOutput {
+                    k: 0.5,
+                    d: 0.5,
+                    slow_d: 0.5,
+                }
+                .into();
+            }
+            _ => {
                 let d_numerator = self.d_numerator.next(input - min);
                 let d_denominator = self.d_denominator.next(max - min);
-                current.k = if min == max {
+                let k = if min == max {
                     0.5
                 } else {
                     (input - min) / (max - min)
                 };
-                current.d = if d_denominator == 0.0 {
+                let d = if d_denominator == 0.0 {
                     0.5
                 } else {
                     d_numerator / d_denominator
                 };
-                current.slow_d = self.slow_d.next(current.d);
-            }
-            None => {
-                let _ = self.d_numerator.next(0.0);
-                let _ = self.d_denominator.next(0.0);
-                let _ = self.slow_d.next(0.0);
-                self.current = StochasticsOutput {
-                    k: 0.5,
-                    d: 0.5,
-                    slow_d: 0.5,
-                }
-                .into();
+                let slow_d = self.slow_d.next(d);
+                self.current = StochasticsOutput { k, d, slow_d }.into();
             }
         }
 
@@ -72,14 +100,12 @@ impl Stochastics {
 }
 impl Default for Stochastics {
     fn default() -> Self {
-        Self {
-            min: Min::new(Self::DEFAULT_N_PERIOD).unwrap(),
-            max: Max::new(Self::DEFAULT_N_PERIOD).unwrap(),
-            d_numerator: Sma::new(Self::DEFAULT_M_PERIOD).unwrap(),
-            d_denominator: Sma::new(Self::DEFAULT_M_PERIOD).unwrap(),
-            slow_d: Sma::new(Self::DEFAULT_X_PERIOD).unwrap(),
-            current: None,
-        }
+        Self::new(
+            Self::DEFAULT_N_PERIOD,
+            Self::DEFAULT_M_PERIOD,
+            Self::DEFAULT_X_PERIOD,
+        )
+        .unwrap()
     }
 }
 impl Indicator for Stochastics {
@@ -102,6 +128,7 @@ impl<Input: Price> Next<&Input> for Stochastics {
 }
 impl Reset for Stochastics {
     fn reset(&mut self) {
+        self.count = 0;
         self.min.reset();
         self.max.reset();
         self.d_numerator.reset();
@@ -110,8 +137,19 @@ impl Reset for Stochastics {
         self.current = None;
     }
 }
+impl WarmupPeriod for Stochastics {
+    fn warmup_period(&self) -> usize {
+        self.max.warmup_period() + self.d_numerator.warmup_period() + self.slow_d.warmup_period()
+    }
+}
+impl Lookback for Stochastics {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StochasticsOutput {
     pub k: f64,
     pub d: f64,
@@ -122,16 +160,16 @@ impl From<(f64, f64, f64)> for StochasticsOutput {
         Self { k, d, slow_d }
     }
 }
-impl Into<(f64, f64, f64)> for StochasticsOutput {
-    fn into(self) -> (f64, f64, f64) {
-        (self.k, self.d, self.slow_d)
+impl From<StochasticsOutput> for (f64, f64, f64) {
+    fn from(x: StochasticsOutput) -> Self {
+        (x.k, x.d, x.slow_d)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_helper::*;
+    use crate::{test_helper::*, SmaSeed};
     use once_cell::sync::Lazy as SyncLazy;
 
     impl Round for StochasticsOutput {
@@ -199,6 +237,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
 
@@ -206,4 +247,27 @@ mod tests {
     fn default() {
         let _: Stochastics = Default::default();
     }
+
+    #[test]
+    fn sma_seed() -> crate::Result<()> {
+        let mut stochastics = Stochastics::with_warmup(N_PERIOD, M_PERIOD, X_PERIOD, SmaSeed)?;
+
+        // Unlike `OUTPUTS`, slow %D starts from the first %D instead of 0.0
+        let outputs = [
+            (0.5, 0.5, 0.5),
+            (1.0, 1.0, 0.75),
+            (1.0, 1.0, 1.0),
+            (0.5, 0.75, 0.875),
+            (0.0, 0.25, 0.5),
+            (0.0, 0.0, 0.125),
+        ];
+        for (x, correct) in INPUTS.iter().zip(outputs) {
+            assert_eq!(
+                Round::round(stochastics.next(x)),
+                Round::round(StochasticsOutput::from(correct))
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,188 @@
+use crate::{
+    Current, Ema, Indicator, InvalidRangeError, Lookback, Next, Parameter, Price, Range, Reset,
+    Result, Seed, Warmup, WarmupPeriod,
+};
+
+/// Tillson T3 Moving Average
+///
+/// Six EMAs chained in series, combined with coefficients derived from the volume factor.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct T3 {
+    emas: [Ema; 6],
+    coefficients: [f64; 4],
+    count: usize,
+}
+impl T3 {
+    pub const DEFAULT_PERIOD: usize = 5;
+    pub const DEFAULT_VOLUME_FACTOR: f64 = 0.7;
+
+    pub fn new(period: usize, volume_factor: f64) -> Result<Self> {
+        Self::with_seed(period, volume_factor, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        period: usize,
+        volume_factor: f64,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, volume_factor, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(period: usize, volume_factor: f64, seed: Seed) -> Result<Self> {
+        let ema = Ema::with_seed(period, seed)?;
+        if !(0.0..=1.0).contains(&volume_factor) {
+            return Err(InvalidRangeError {
+                param: Parameter::new("volume_factor", volume_factor),
+                range: Range::BothBounded { min: 0.0, max: 1.0 },
+            }
+            .into());
+        }
+
+        let a = volume_factor;
+        let coefficients = [
+            -a * a * a,
+            3.0 * a * a + 3.0 * a * a * a,
+            -6.0 * a * a - 3.0 * a - 3.0 * a * a * a,
+            1.0 + 3.0 * a + a * a * a + 3.0 * a * a,
+        ];
+
+        Ok(Self {
+            emas: [
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema.clone(),
+                ema,
+            ],
+            coefficients,
+            count: 0,
+        })
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let mut x = input;
+        for ema in self.emas.iter_mut() {
+            x = ema.next(x);
+        }
+        self.current().unwrap()
+    }
+}
+impl Default for T3 {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD, Self::DEFAULT_VOLUME_FACTOR).unwrap()
+    }
+}
+
+impl Indicator for T3 {
+    type Output = f64;
+}
+impl Current for T3 {
+    fn current(&self) -> Option<Self::Output> {
+        let [c1, c2, c3, c4] = self.coefficients;
+        match (
+            self.emas[2].current(),
+            self.emas[3].current(),
+            self.emas[4].current(),
+            self.emas[5].current(),
+        ) {
+            (Some(e3), Some(e4), Some(e5), Some(e6)) => {
+                (c1 * e6 + c2 * e5 + c3 * e4 + c4 * e3).into()
+            }
+            _ => None,
+        }
+    }
+}
+impl Next<f64> for T3 {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for T3 {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for T3 {
+    fn reset(&mut self) {
+        self.count = 0;
+        for ema in self.emas.iter_mut() {
+            ema.reset();
+        }
+    }
+}
+impl WarmupPeriod for T3 {
+    fn warmup_period(&self) -> usize {
+        self.emas.iter().map(WarmupPeriod::warmup_period).sum()
+    }
+}
+impl Lookback for T3 {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    const VOLUME_FACTOR: f64 = 0.7;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        100.0,
+        100.30754688,
+        101.26435937,
+        101.87787109,
+        102.75000781,
+        104.41095508,
+    ];
+
+    test_indicator! {
+        new: T3::new(PERIOD, VOLUME_FACTOR),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [T3::new(0, 0.7), T3::new(3, -0.1), T3::new(3, 1.1)],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: T3 = Default::default();
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Current, Indicator, Lookback, Next, Price, Reset, Result, Sma, Warmup, WarmupPeriod};
+
+/// Triangular Moving Average
+///
+/// SMA of SMA, so that the weights form a triangle centered on the middle of the period.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trima {
+    sma: Sma,
+    sma_sma: Sma,
+    count: usize,
+}
+impl Trima {
+    pub fn new(period: usize) -> Result<Self> {
+        // (n / 2, n / 2 + 1) for even n, ((n + 1) / 2, (n + 1) / 2) for odd n
+        let first_period = period.div_ceil(2);
+        let second_period = period / 2 + 1;
+        Ok(Self {
+            sma: Sma::new(first_period)?,
+            sma_sma: Sma::new(second_period)?,
+            count: 0,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let sma = self.sma.next(input);
+        self.sma_sma.next(sma)
+    }
+}
+
+impl Indicator for Trima {
+    type Output = f64;
+}
+impl Current for Trima {
+    fn current(&self) -> Option<Self::Output> {
+        self.sma_sma.current()
+    }
+}
+impl Next<f64> for Trima {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Trima {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Trima {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.sma.reset();
+        self.sma_sma.reset();
+    }
+}
+impl WarmupPeriod for Trima {
+    fn warmup_period(&self) -> usize {
+        self.sma.warmup_period() + self.sma_sma.warmup_period()
+    }
+}
+impl Lookback for Trima {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        100.0,
+        100.16666667,
+        100.83333333,
+        101.66666667,
+        102.5,
+        103.66666667,
+    ];
+
+    test_indicator! {
+        new: Trima::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: Trima::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn odd_period() -> crate::Result<()> {
+        let mut trima = Trima::new(5)?;
+        let outputs = [
+            100.0,
+            100.11111111,
+            100.55555556,
+            101.22222222,
+            102.11111111,
+            103.11111111,
+        ];
+
+        for (x, correct) in INPUTS.iter().zip(outputs) {
+            assert_eq!(Round::round(trima.next(x)), Round::round(correct));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)]
+use crate::float::Float;
+use crate::{
+    Current, Ema, Indicator, Lookback, Next, Price, Reset, Result, Seed, Warmup, WarmupPeriod,
+};
+
+/// Triple Exponential Average
+///
+/// 1-bar change of the triple [`Ema`] of the log price,
+/// which approximates the rate of change of the triple smoothed price as a ratio.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trix {
+    emas: [Ema; 3],
+    prev_ema: Option<f64>,
+    count: usize,
+    current: Option<f64>,
+}
+impl Trix {
+    pub const DEFAULT_PERIOD: usize = 15;
+
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_seed(period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::with_seed(period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(period: usize, seed: Seed) -> Result<Self> {
+        let ema = Ema::with_seed(period, seed)?;
+        Ok(Self {
+            emas: [ema.clone(), ema.clone(), ema],
+            prev_ema: None,
+            count: 0,
+            current: None,
+        })
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let mut ema = input.ln();
+        for x in self.emas.iter_mut() {
+            ema = x.next(ema);
+        }
+        let trix = match self.prev_ema {
+            Some(prev_ema) => ema - prev_ema,
+            None => 0.0,
+        };
+        self.prev_ema = ema.into();
+        self.current = trix.into();
+        trix
+    }
+}
+impl Default for Trix {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for Trix {
+    type Output = f64;
+}
+impl Current for Trix {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for Trix {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Trix {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Trix {
+    fn reset(&mut self) {
+        self.count = 0;
+        for ema in self.emas.iter_mut() {
+            ema.reset();
+        }
+        self.prev_ema = None;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Trix {
+    fn warmup_period(&self) -> usize {
+        // Plus the bar with no previous EMA to compare with
+        self.emas
+            .iter()
+            .map(WarmupPeriod::warmup_period)
+            .sum::<usize>()
+            + 1
+    }
+}
+impl Lookback for Trix {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        0.0, 0.00247533, 0.00248146, 0.00552448, 0.00552731, 0.00804655, 0.00743006,
+    ];
+
+    test_indicator! {
+        new: Trix::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: Trix::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Trix = Default::default();
+    }
+}
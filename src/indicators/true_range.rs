@@ -0,0 +1,134 @@
+use crate::{Close, Current, High, Indicator, Lookback, Low, Next, Reset, Warmup, WarmupPeriod};
+
+/// True Range
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrueRange {
+    prev_close: Option<f64>,
+    current: Option<f64>,
+}
+impl TrueRange {
+    pub fn new() -> Self {
+        Self {
+            prev_close: None,
+            current: None,
+        }
+    }
+
+    pub fn with_warmup<W: Warmup>(_warmup: W) -> W::Apply<Self> {
+        W::apply(Self::new())
+    }
+
+    fn _next(&mut self, high: f64, low: f64, close: f64) -> <Self as Indicator>::Output {
+        let true_range = match self.prev_close {
+            Some(prev_close) => high.max(prev_close) - low.min(prev_close),
+            None => high - low,
+        };
+        self.prev_close = close.into();
+        self.current = true_range.into();
+        self.current().unwrap()
+    }
+}
+impl Default for TrueRange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for TrueRange {
+    type Output = f64;
+}
+impl Current for TrueRange {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<(f64, f64, f64)> for TrueRange {
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close)
+    }
+}
+impl<Input: High + Low + Close> Next<&Input> for TrueRange {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close())
+    }
+}
+impl Reset for TrueRange {
+    fn reset(&mut self) {
+        self.prev_close = None;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for TrueRange {
+    fn warmup_period(&self) -> usize {
+        0
+    }
+}
+impl Lookback for TrueRange {
+    fn is_ready(&self) -> bool {
+        self.current.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.0),
+            (12.0, 10.0, 11.0),
+            (15.0, 13.0, 14.0),
+            (13.0, 12.0, 12.5),
+            (12.0, 10.0, 11.0),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[2.0, 2.0, 2.0, 4.0, 2.0, 2.5];
+
+    test_indicator! {
+        new: crate::Result::Ok(TrueRange::new()),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+        }
+    }
+}
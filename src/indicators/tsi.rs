@@ -0,0 +1,196 @@
+use crate::{
+    Current, Ema, Indicator, InvalidBinaryRelationError, Lookback, Next, Parameter, Price, Reset,
+    Result, Seed, Warmup, WarmupPeriod,
+};
+
+/// True Strength Index
+///
+/// 1-bar momentum smoothed by a long and then a short [`Ema`],
+/// divided by its absolute value smoothed the same way.
+///
+/// Range in \[-1.0, 1.0\]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tsi {
+    long: Ema,
+    short: Ema,
+    abs_long: Ema,
+    abs_short: Ema,
+    seed: Seed,
+    prev_input: Option<f64>,
+    count: usize,
+    current: Option<f64>,
+}
+impl Tsi {
+    pub const DEFAULT_LONG_PERIOD: usize = 25;
+    pub const DEFAULT_SHORT_PERIOD: usize = 13;
+
+    pub fn new(long_period: usize, short_period: usize) -> Result<Self> {
+        Self::with_seed(long_period, short_period, Seed::First)
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        long_period: usize,
+        short_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::with_seed(long_period, short_period, W::SEED).map(W::apply)
+    }
+
+    pub(crate) fn with_seed(long_period: usize, short_period: usize, seed: Seed) -> Result<Self> {
+        if short_period >= long_period {
+            return Err(InvalidBinaryRelationError {
+                operator: "<",
+                lhs: Parameter::new("short_period", short_period),
+                rhs: Parameter::new("long_period", long_period),
+            }
+            .into());
+        }
+
+        let long = Ema::with_seed(long_period, seed)?;
+        let short = Ema::with_seed(short_period, seed)?;
+        Ok(Self {
+            abs_long: long.clone(),
+            abs_short: short.clone(),
+            long,
+            short,
+            seed,
+            prev_input: None,
+            count: 0,
+            current: None,
+        })
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let momentum = match self.prev_input {
+            Some(prev_input) => input - prev_input,
+            None if self.seed == Seed::First => 0.0,
+            // A mean seed averages real momentum only
+            None => {
+                self.prev_input = input.into();
+                self.current = 0.0.into();
+                return 0.0;
+            }
+        };
+        self.prev_input = input.into();
+
+        let numerator = self.short.next(self.long.next(momentum));
+        let denominator = self.abs_short.next(self.abs_long.next(momentum.abs()));
+        let tsi = if denominator == 0.0 {
+            0.0
+        } else {
+            numerator / denominator
+        };
+        self.current = tsi.into();
+        tsi
+    }
+}
+impl Default for Tsi {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_LONG_PERIOD, Self::DEFAULT_SHORT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for Tsi {
+    type Output = f64;
+}
+impl Current for Tsi {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for Tsi {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Tsi {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Tsi {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.long.reset();
+        self.short.reset();
+        self.abs_long.reset();
+        self.abs_short.reset();
+        self.prev_input = None;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Tsi {
+    fn warmup_period(&self) -> usize {
+        // The first input has no momentum
+        1 + self.long.warmup_period() + self.short.warmup_period()
+    }
+}
+impl Lookback for Tsi {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const LONG_PERIOD: usize = 4;
+    const SHORT_PERIOD: usize = 2;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 102.0, 101.0, 104.0, 103.0, 106.0, 105.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        0.0, 1.0, 0.30232558, 0.64616681, 0.37534611, 0.60423831, 0.39204736,
+    ];
+
+    test_indicator! {
+        new: Tsi::new(LONG_PERIOD, SHORT_PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [
+                    Tsi::new(2, 0),
+                    Tsi::new(2, 2),
+                    Tsi::new(2, 3),
+                ],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Tsi = Default::default();
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    Current, Indicator, InvalidRangeError, Lookback, Next, Parameter, Price, Range, Reset, Result,
+    Sma, Warmup, WarmupPeriod,
+};
+
+/// Variable Index Dynamic Average
+///
+/// The smoothing constant of the EMA is scaled by the absolute value of the Chande Momentum Oscillator.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vidya {
+    alpha: f64,
+    up: Sma,
+    down: Sma,
+    prev_input: Option<f64>,
+    count: usize,
+    current: Option<VidyaOutput>,
+}
+impl Vidya {
+    pub const DEFAULT_PERIOD: usize = 9;
+    pub const DEFAULT_CMO_PERIOD: usize = 9;
+
+    pub fn new(period: usize, cmo_period: usize) -> Result<Self> {
+        if period < 1 {
+            return Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into());
+        }
+
+        Ok(Self {
+            alpha: 2.0 / (period + 1) as f64,
+            up: Sma::new(cmo_period)?,
+            down: Sma::new(cmo_period)?,
+            prev_input: None,
+            count: 0,
+            current: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(
+        period: usize,
+        cmo_period: usize,
+        _warmup: W,
+    ) -> Result<W::Apply<Self>> {
+        Self::new(period, cmo_period).map(W::apply)
+    }
+
+    fn _next(&mut self, input: f64) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        let change = match self.prev_input {
+            Some(prev_input) => input - prev_input,
+            None => 0.0,
+        };
+        self.prev_input = input.into();
+
+        let up = self.up.next(change.max(0.0));
+        let down = self.down.next((-change).max(0.0));
+        let cmo = if up + down <= 0.0 {
+            0.0
+        } else {
+            (up - down) / (up + down)
+        };
+        let alpha = self.alpha * cmo.abs();
+
+        match &mut self.current {
+            Some(current) => {
+                current.vidya += (input - current.vidya) * alpha;
+                current.alpha = alpha;
+            }
+            None => {
+                self.current = VidyaOutput {
+                    vidya: input,
+                    alpha,
+                }
+                .into();
+            }
+        }
+        self.current().unwrap()
+    }
+}
+impl Default for Vidya {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD, Self::DEFAULT_CMO_PERIOD).unwrap()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VidyaOutput {
+    pub vidya: f64,
+    /// Smoothing constant scaled by the absolute CMO
+    pub alpha: f64,
+}
+impl From<(f64, f64)> for VidyaOutput {
+    fn from((vidya, alpha): (f64, f64)) -> Self {
+        Self { vidya, alpha }
+    }
+}
+impl From<VidyaOutput> for (f64, f64) {
+    fn from(x: VidyaOutput) -> Self {
+        (x.vidya, x.alpha)
+    }
+}
+
+impl Indicator for Vidya {
+    type Output = VidyaOutput;
+}
+impl Current for Vidya {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<f64> for Vidya {
+    fn next(&mut self, input: f64) -> Self::Output {
+        self._next(input)
+    }
+}
+impl<Input: Price> Next<&Input> for Vidya {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl Reset for Vidya {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.up.reset();
+        self.down.reset();
+        self.prev_input = None;
+        self.current = None;
+    }
+}
+impl WarmupPeriod for Vidya {
+    fn warmup_period(&self) -> usize {
+        // The CMO needs `cmo_period` changes, and the first input has none
+        self.up.warmup_period() + 1
+    }
+}
+impl Lookback for Vidya {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+    use once_cell::sync::Lazy as SyncLazy;
+
+    impl Round for VidyaOutput {
+        fn round(self) -> Self {
+            Self {
+                vidya: Round::round(self.vidya),
+                alpha: Round::round(self.alpha),
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 3;
+    const CMO_PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0, 106.0, 108.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: SyncLazy<Box<[VidyaOutput]>> = SyncLazy::new(|| {
+        [
+            (100.00000000, 0.00000000),
+            (100.50000000, 0.50000000),
+            (101.75000000, 0.50000000),
+            (101.81250000, 0.25000000),
+            (102.46875000, 0.30000000),
+            (103.97916667, 0.33333333),
+            (104.65277778, 0.33333333),
+            (105.76851852, 0.33333333),
+        ]
+        .into_iter()
+        .map(VidyaOutput::from)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+
+    test_indicator! {
+        new: Vidya::new(PERIOD, CMO_PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                news: [Vidya::new(0, 3), Vidya::new(3, 0)],
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: Vidya = Default::default();
+    }
+}
@@ -1,55 +1,81 @@
-use crate::{Current, Indicator, Next, Price, Reset, Volume};
+use crate::{
+    Current, Indicator, Lookback, Next, Number, Price, Reset, Volume, Warmup, WarmupPeriod,
+};
 
 /// Volume Weighted Average Price
 #[derive(Debug, Clone)]
-pub struct Vwap {
-    current: Option<f64>,
-    total_volume: f64,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vwap<T = f64> {
+    current: Option<T>,
+    total_price_volume: T,
+    total_volume: T,
 }
 impl Vwap {
     pub fn new() -> Self {
-        Self {
-            current: None,
-            total_volume: 0.0,
-        }
+        Self::default()
     }
 
-    fn _next(&mut self, price: f64, volume: f64) -> <Self as Indicator>::Output {
+    pub fn with_warmup<W: Warmup>(_warmup: W) -> W::Apply<Self> {
+        W::apply(Self::new())
+    }
+}
+impl<T: Number> Vwap<T> {
+    fn _next(&mut self, price: T, volume: T) -> <Self as Indicator>::Output {
+        self.total_price_volume += price * volume;
         self.total_volume += volume;
-        match &mut self.current {
-            Some(current) => {
-                *current += (price - *current) * volume / self.total_volume;
-            }
-            None => {
-                self.current = price.into();
-            }
+        // Both totals are plain sums, so only the division rounds
+        let vwap = if self.total_volume == T::ZERO {
+            price
+        } else {
+            self.total_price_volume / self.total_volume
+        };
+        self.current = vwap.into();
+        vwap
+    }
+}
+impl<T: Number> Default for Vwap<T> {
+    fn default() -> Self {
+        Self {
+            current: None,
+            total_price_volume: T::ZERO,
+            total_volume: T::ZERO,
         }
-        self.current().unwrap()
     }
 }
 
-impl Indicator for Vwap {
-    type Output = f64;
+impl<T: Number> Indicator for Vwap<T> {
+    type Output = T;
 }
-impl Current for Vwap {
+impl<T: Number> Current for Vwap<T> {
     fn current(&self) -> Option<Self::Output> {
         self.current
     }
 }
-impl Next<(f64, f64)> for Vwap {
-    fn next(&mut self, (price, volume): (f64, f64)) -> Self::Output {
+impl<T: Number> Next<(T, T)> for Vwap<T> {
+    fn next(&mut self, (price, volume): (T, T)) -> Self::Output {
         self._next(price, volume)
     }
 }
-impl<Input: Price + Volume> Next<&Input> for Vwap {
+impl<T: Number, Input: Price<T> + Volume<T>> Next<&Input> for Vwap<T> {
     fn next(&mut self, input: &Input) -> Self::Output {
         self._next(input.price(), input.volume())
     }
 }
-impl Reset for Vwap {
+impl<T: Number> Reset for Vwap<T> {
     fn reset(&mut self) {
         self.current = None;
-        self.total_volume = 0.0;
+        self.total_price_volume = T::ZERO;
+        self.total_volume = T::ZERO;
+    }
+}
+impl<T: Number> WarmupPeriod for Vwap<T> {
+    fn warmup_period(&self) -> usize {
+        0
+    }
+}
+impl<T: Number> Lookback for Vwap<T> {
+    fn is_ready(&self) -> bool {
+        self.current.is_some()
     }
 }
 
@@ -104,6 +130,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| (x.price(), x.volume())),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.price(), x.volume())),
+            },
         }
     }
 }
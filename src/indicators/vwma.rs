@@ -1,17 +1,47 @@
+#[cfg(feature = "alloc")]
+use crate::Warmup;
 use crate::{
-    Current, Indicator, InvalidRangeError, Next, Parameter, Price, Range, Reset, Result, Volume,
+    collections::NeumaierSum, storage::check_capacity, Current, DefaultStorage, Indicator, Inline,
+    InvalidRangeError, Lookback, Next, Number, Parameter, Price, Range, Reset, Result, Ring,
+    Storage, Volume, WarmupPeriod,
 };
-use alloc::collections::VecDeque;
 
 /// Volume Weighted Moving Average
+///
+/// Both running sums are compensated against floating-point drift.
 #[derive(Debug, Clone)]
-pub struct Vwma {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, S::Ring<(T, T)>: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, S::Ring<(T, T)>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Vwma<T: Number = f64, S: Storage = DefaultStorage> {
     period: usize,
-    ring: VecDeque<(f64, f64)>,
-    sum: Option<(f64, f64)>,
+    ring: S::Ring<(T, T)>,
+    sum: Option<(NeumaierSum<T>, NeumaierSum<T>)>,
+    count: usize,
 }
+
+/// [`Vwma`] over a period of `P` fixed at compile time, kept inline without allocating
+///
+/// Built by `VwmaN::<P>::default()`, and outputs exactly what `Vwma::new(P)` does.
+pub type VwmaN<const P: usize, T = f64> = Vwma<T, Inline<P>>;
+#[cfg(feature = "alloc")]
 impl Vwma {
     pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+}
+impl<T: Number, S: Storage> Vwma<T, S> {
+    /// Same as `new`, in any [`Number`] type and [`Storage`]
+    pub fn with_period(period: usize) -> Result<Self> {
         if period < 1 {
             Err(InvalidRangeError {
                 param: Parameter::new("period", period),
@@ -19,33 +49,36 @@ impl Vwma {
             }
             .into())
         } else {
+            check_capacity::<S>(period, period)?;
             Ok(Self {
                 period,
-                ring: VecDeque::with_capacity(period),
+                ring: Ring::with_capacity(period),
                 sum: None,
+                count: 0,
             })
         }
     }
 
-    fn _next(&mut self, price: f64, volume: f64) -> <Self as Indicator>::Output {
+    fn _next(&mut self, price: T, volume: T) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
         match &mut self.sum {
             Some((sum, total_volume)) => {
                 let (old_price, old_volume) = self.ring.pop_front().unwrap();
                 self.ring.push_back((price, volume));
 
-                *sum -= old_price * old_volume;
-                *total_volume -= old_volume;
+                sum.sub(old_price * old_volume);
+                total_volume.sub(old_volume);
 
-                *sum += price * volume;
-                *total_volume += volume;
+                sum.add(price * volume);
+                total_volume.add(volume);
             }
             None => {
                 for _ in 0..self.period {
                     self.ring.push_back((price, volume));
                 }
                 self.sum = (
-                    price * volume * self.period as f64,
-                    volume * self.period as f64,
+                    NeumaierSum::new(price * volume * T::from_usize(self.period)),
+                    NeumaierSum::new(volume * T::from_usize(self.period)),
                 )
                     .into();
             }
@@ -53,31 +86,49 @@ impl Vwma {
         self.current().unwrap()
     }
 }
+impl<T: Number, const P: usize> Default for Vwma<T, Inline<P>> {
+    fn default() -> Self {
+        const { assert!(0 < P, "period must be at least 1") };
+        Self::with_period(P).unwrap()
+    }
+}
 
-impl Indicator for Vwma {
-    type Output = f64;
+impl<T: Number, S: Storage> Indicator for Vwma<T, S> {
+    type Output = T;
 }
-impl Current for Vwma {
+impl<T: Number, S: Storage> Current for Vwma<T, S> {
     fn current(&self) -> Option<Self::Output> {
-        self.sum.map(|(sum, total_volume)| sum / total_volume)
+        self.sum
+            .map(|(sum, total_volume)| sum.value() / total_volume.value())
     }
 }
-impl Next<(f64, f64)> for Vwma {
-    fn next(&mut self, (price, volume): (f64, f64)) -> Self::Output {
+impl<T: Number, S: Storage> Next<(T, T)> for Vwma<T, S> {
+    fn next(&mut self, (price, volume): (T, T)) -> Self::Output {
         self._next(price, volume)
     }
 }
-impl<Input: Price + Volume> Next<&Input> for Vwma {
+impl<T: Number, S: Storage, Input: Price<T> + Volume<T>> Next<&Input> for Vwma<T, S> {
     fn next(&mut self, input: &Input) -> Self::Output {
         self._next(input.price(), input.volume())
     }
 }
-impl Reset for Vwma {
+impl<T: Number, S: Storage> Reset for Vwma<T, S> {
     fn reset(&mut self) {
+        self.count = 0;
         self.ring.clear();
         self.sum = None;
     }
 }
+impl<T: Number, S: Storage> WarmupPeriod for Vwma<T, S> {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl<T: Number, S: Storage> Lookback for Vwma<T, S> {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -134,6 +185,47 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| (x.price(), x.volume())),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.price(), x.volume())),
+            },
+        }
+    }
+
+    #[test]
+    fn long_run_drift() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        const UPDATES: usize = 2_000_000;
+        let mut vwma = Vwma::new(PERIOD)?;
+
+        let inputs: Vec<(f64, f64)> = RANDOM_DATA
+            .iter()
+            .map(|x| (1e6 + x.price(), 1e3 + x.volume()))
+            .cycle()
+            .take(UPDATES)
+            .collect();
+        for input in inputs.iter() {
+            let _ = vwma.next(*input);
+        }
+
+        let window = &inputs[UPDATES - PERIOD..];
+        let sum: f64 = window.iter().map(|(price, volume)| price * volume).sum();
+        let total_volume: f64 = window.iter().map(|(_, volume)| volume).sum();
+        let correct = sum / total_volume;
+        assert!((vwma.current().unwrap() - correct).abs() < 1e-8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn const_period() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        let mut vwma = Vwma::new(PERIOD)?;
+        let mut inline = VwmaN::<PERIOD>::default();
+
+        for x in RANDOM_DATA.iter() {
+            assert_eq!(inline.next(x), vwma.next(x));
         }
+
+        Ok(())
     }
 }
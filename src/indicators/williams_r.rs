@@ -0,0 +1,165 @@
+use crate::{
+    Close, Current, High, Indicator, Lookback, Low, Max, Min, Next, Reset, Result, Warmup,
+    WarmupPeriod,
+};
+
+/// Williams %R
+///
+/// `-(highest high - close) / (highest high - lowest low)` over the last `period` bars.
+/// Range in \[-1.0, 0.0\]; outputs -0.5 while the highest high equals the lowest low.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WilliamsR {
+    max: Max,
+    min: Min,
+    current: Option<f64>,
+}
+impl WilliamsR {
+    pub const DEFAULT_PERIOD: usize = 14;
+
+    pub fn new(period: usize) -> Result<Self> {
+        let max = Max::new(period)?;
+        let min = Min::new(period)?;
+        Ok(Self {
+            max,
+            min,
+            current: None,
+        })
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+
+    fn _next(&mut self, high: f64, low: f64, close: f64) -> <Self as Indicator>::Output {
+        let max = self.max.next(high);
+        let min = self.min.next(low);
+        let williams_r = if max == min {
+            -0.5
+        } else {
+            -(max - close) / (max - min)
+        };
+        self.current = williams_r.into();
+        williams_r
+    }
+}
+impl Default for WilliamsR {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PERIOD).unwrap()
+    }
+}
+
+impl Indicator for WilliamsR {
+    type Output = f64;
+}
+impl Current for WilliamsR {
+    fn current(&self) -> Option<Self::Output> {
+        self.current
+    }
+}
+impl Next<(f64, f64, f64)> for WilliamsR {
+    fn next(&mut self, (high, low, close): (f64, f64, f64)) -> Self::Output {
+        self._next(high, low, close)
+    }
+}
+impl<Input: High + Low + Close> Next<&Input> for WilliamsR {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.high(), input.low(), input.close())
+    }
+}
+impl Reset for WilliamsR {
+    fn reset(&mut self) {
+        self.max.reset();
+        self.min.reset();
+        self.current = None;
+    }
+}
+impl WarmupPeriod for WilliamsR {
+    fn warmup_period(&self) -> usize {
+        self.max.warmup_period()
+    }
+}
+impl Lookback for WilliamsR {
+    fn is_ready(&self) -> bool {
+        self.max.is_ready() && self.min.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+
+    #[derive(Clone)]
+    struct TestItem(f64, f64, f64);
+    impl High for TestItem {
+        fn high(&self) -> f64 {
+            self.0
+        }
+    }
+    impl Low for TestItem {
+        fn low(&self) -> f64 {
+            self.1
+        }
+    }
+    impl Close for TestItem {
+        fn close(&self) -> f64 {
+            self.2
+        }
+    }
+
+    const PERIOD: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [
+            (10.0, 10.0, 10.0),
+            (11.0, 9.0, 10.5),
+            (12.0, 10.0, 11.0),
+            (11.5, 9.5, 10.0),
+            (10.0, 8.5, 9.0),
+            (12.0, 9.0, 11.5),
+        ]
+        .into_iter()
+        .map(|(high, low, close)| TestItem(high, low, close))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[
+        -0.5,
+        -0.25,
+        -0.33333333,
+        -0.66666667,
+        -0.85714286,
+        -0.14285714,
+    ];
+
+    test_indicator! {
+        new: WilliamsR::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| (x.high(), x.low(), x.close())),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: WilliamsR::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low(), x.close())),
+            },
+        }
+    }
+
+    #[test]
+    fn default() {
+        let _: WilliamsR = Default::default();
+    }
+}
@@ -0,0 +1,237 @@
+#[cfg(feature = "alloc")]
+use crate::Warmup;
+use crate::{
+    storage::check_capacity, Current, DefaultStorage, Indicator, InvalidRangeError, Lookback, Next,
+    Number, Parameter, Price, Range, Reset, Result, Ring, Storage, WarmupPeriod,
+};
+
+/// Weighted Moving Average
+///
+/// The running sums are recomputed from the ring every `period` bars,
+/// so rounding errors cannot pile up on long-running feeds.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize, S::Ring<T>: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, S::Ring<T>: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Wma<T: Number = f64, S: Storage = DefaultStorage> {
+    period: usize,
+    ring: S::Ring<T>,
+    sum_weighted_sum: Option<(T, T)>,
+    since_recompute: usize,
+    count: usize,
+}
+#[cfg(feature = "alloc")]
+impl Wma {
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_period(period)
+    }
+
+    pub fn with_warmup<W: Warmup>(period: usize, _warmup: W) -> Result<W::Apply<Self>> {
+        Self::new(period).map(W::apply)
+    }
+}
+impl<T: Number, S: Storage> Wma<T, S> {
+    /// Same as `new`, in any [`Number`] type and [`Storage`]
+    pub fn with_period(period: usize) -> Result<Self> {
+        if period < 1 {
+            Err(InvalidRangeError {
+                param: Parameter::new("period", period),
+                range: Range::LowerBounded { min: 1 },
+            }
+            .into())
+        } else {
+            check_capacity::<S>(period, period)?;
+            Ok(Self {
+                period,
+                ring: Ring::with_capacity(period),
+                sum_weighted_sum: None,
+                since_recompute: 0,
+                count: 0,
+            })
+        }
+    }
+
+    /// Sum of the weights: `1 + 2 + ... + period`
+    fn total_weight(&self) -> T {
+        T::from_usize(self.period * (self.period + 1) / 2)
+    }
+
+    fn _next(&mut self, input: T) -> <Self as Indicator>::Output {
+        self.count = self.count.saturating_add(1);
+        match &mut self.sum_weighted_sum {
+            Some((sum, weighted_sum)) => {
+                // Shifting the window decrements the weight of every value by one,
+                // and the new value takes the largest weight.
+                *weighted_sum += input * T::from_usize(self.period) - *sum;
+                *sum -= self.ring.pop_front().unwrap();
+                self.ring.push_back(input);
+                *sum += input;
+
+                self.since_recompute += 1;
+                if self.period <= self.since_recompute {
+                    self.since_recompute = 0;
+                    self.sum_weighted_sum = Self::recompute(&self.ring).into();
+                }
+            }
+            None => {
+                for _ in 0..self.period {
+                    self.ring.push_back(input);
+                }
+                self.sum_weighted_sum = (
+                    input * T::from_usize(self.period),
+                    input * self.total_weight(),
+                )
+                    .into();
+            }
+        }
+        self.current().unwrap()
+    }
+
+    /// Sum and weighted sum of the ring, where the oldest value has a weight of 1
+    fn recompute(ring: &S::Ring<T>) -> (T, T) {
+        ring.iter()
+            .enumerate()
+            .fold((T::ZERO, T::ZERO), |(sum, weighted_sum), (i, &x)| {
+                (sum + x, weighted_sum + x * T::from_usize(i + 1))
+            })
+    }
+}
+
+impl<T: Number, S: Storage> Indicator for Wma<T, S> {
+    type Output = T;
+}
+impl<T: Number, S: Storage> Current for Wma<T, S> {
+    fn current(&self) -> Option<Self::Output> {
+        self.sum_weighted_sum
+            .map(|(_, weighted_sum)| weighted_sum / self.total_weight())
+    }
+}
+impl_next_for_numbers!(storage Wma);
+impl<T: Number, S: Storage, Input: Price<T>> Next<&Input> for Wma<T, S> {
+    fn next(&mut self, input: &Input) -> Self::Output {
+        self._next(input.price())
+    }
+}
+impl<T: Number, S: Storage> Reset for Wma<T, S> {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.ring.clear();
+        self.sum_weighted_sum = None;
+        self.since_recompute = 0;
+    }
+}
+impl<T: Number, S: Storage> WarmupPeriod for Wma<T, S> {
+    fn warmup_period(&self) -> usize {
+        self.period - 1
+    }
+}
+impl<T: Number, S: Storage> Lookback for Wma<T, S> {
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy as SyncLazy;
+
+    use super::*;
+    use crate::test_helper::*;
+    use alloc::collections::VecDeque;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+
+    const PERIOD: usize = 4;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 103.0, 102.0, 104.0, 107.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    static OUTPUTS: &[f64] = &[100.0, 100.4, 101.5, 101.9, 102.9, 104.7];
+
+    test_indicator! {
+        new: Wma::new(PERIOD),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            new_invalid_parameter: {
+                new: Wma::new(0),
+            },
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        }
+    }
+
+    #[test]
+    fn naive() -> crate::Result<()> {
+        let mut wma = Wma::new(PERIOD)?;
+        let mut ring = VecDeque::from(vec![RANDOM_DATA[0].price(); PERIOD]);
+
+        for x in RANDOM_DATA.iter().map(|x| x.price()) {
+            let _ = ring.pop_front();
+            ring.push_back(x);
+            let correct = ring
+                .iter()
+                .enumerate()
+                .map(|(i, x)| (i + 1) as f64 * x)
+                .sum::<f64>()
+                / (PERIOD * (PERIOD + 1) / 2) as f64;
+            assert!((wma.next(x) - correct).abs() < PRECISION);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_run_drift() -> crate::Result<()> {
+        const PERIOD: usize = 20;
+        const UPDATES: usize = 2_000_000;
+        let mut wma = Wma::new(PERIOD)?;
+
+        // A large offset with small fluctuations is where the running sums drift the most
+        let inputs: Vec<f64> = RANDOM_DATA
+            .iter()
+            .map(|x| 1e6 + x.price())
+            .cycle()
+            .take(UPDATES)
+            .collect();
+        for input in inputs.iter() {
+            let _ = wma.next(*input);
+        }
+
+        let window = &inputs[UPDATES - PERIOD..];
+        let correct = window
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (i + 1) as f64 * x)
+            .sum::<f64>()
+            / (PERIOD * (PERIOD + 1) / 2) as f64;
+        assert!((wma.current().unwrap() - correct).abs() < 1e-8);
+
+        Ok(())
+    }
+}
@@ -17,20 +17,28 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(dead_code)]
-#![cfg_attr(test, feature(once_cell))]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 #[cfg(test)]
 #[macro_use]
 mod test_helper;
 
+mod collections;
+#[cfg(not(feature = "std"))]
+mod float;
+#[macro_use]
+pub mod number;
+
 pub mod error;
 pub mod indicator_ext;
 pub mod indicators;
 
 pub mod indicator_iterator;
 pub mod operators;
+pub mod storage;
+pub mod warmup;
 
 #[cfg(feature = "stream")]
 pub mod indicator_stream;
@@ -38,6 +46,9 @@ pub mod indicator_stream;
 pub use error::*;
 pub use indicator_ext::*;
 pub use indicators::*;
+pub use number::*;
+pub use storage::*;
+pub use warmup::*;
 
 /// Indicator
 pub trait Indicator {
@@ -59,24 +70,24 @@ pub trait Reset {
     fn reset(&mut self);
 }
 
-pub trait High {
-    fn high(&self) -> f64;
+pub trait High<T = f64> {
+    fn high(&self) -> T;
 }
-pub trait Low {
-    fn low(&self) -> f64;
+pub trait Low<T = f64> {
+    fn low(&self) -> T;
 }
-pub trait Open {
-    fn open(&self) -> f64;
+pub trait Open<T = f64> {
+    fn open(&self) -> T;
 }
-pub trait Close {
-    fn close(&self) -> f64;
+pub trait Close<T = f64> {
+    fn close(&self) -> T;
 }
-pub trait Volume {
-    fn volume(&self) -> f64;
+pub trait Volume<T = f64> {
+    fn volume(&self) -> T;
 }
 
-pub trait Price {
-    fn price(&self) -> f64;
+pub trait Price<T = f64> {
+    fn price(&self) -> T;
 }
 
 pub trait Candlestick: High + Low + Open + Close + Volume {
@@ -113,6 +124,7 @@ pub trait Candlestick: High + Low + Open + Close + Volume {
 impl<T: High + Low + Open + Close + Volume> Candlestick for T {}
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PivotPoint {
     pub r3: f64,
     pub r2: f64,
@@ -122,3 +134,17 @@ pub struct PivotPoint {
     pub s2: f64,
     pub s3: f64,
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::test_helper::*;
+
+    #[test]
+    fn serde() {
+        for x in RANDOM_DATA.iter().take(100) {
+            let pivot_point = x.pivot_point();
+            assert_eq!(round_trip(&pivot_point), pivot_point);
+        }
+    }
+}
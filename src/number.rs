@@ -0,0 +1,178 @@
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// Numeric type the generic indicators compute in
+///
+/// Implemented for `f64`, which every indicator defaults to, and `f32`.
+/// With the `decimal` feature, it is also implemented for [`rust_decimal::Decimal`],
+/// whose sums and products do not round.
+///
+/// `new` builds an `f64` indicator, and `with_period` builds one in any number type.
+/// Generic indicators read `Price<T>`, `Volume<T>` and so on from the input.
+///
+/// # Example
+///
+/// ```
+/// # use indicator::*;
+/// # fn main() {
+/// let mut sma = Sma::<f32>::with_period(2).unwrap();
+///
+/// assert_eq!(sma.next(1.0f32), 1.0);
+/// assert_eq!(sma.next(2.0f32), 1.5);
+/// # }
+/// ```
+pub trait Number:
+    Copy
+    + Default
+    + PartialOrd
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Convert a period or a count
+    fn from_usize(n: usize) -> Self;
+
+    fn abs(self) -> Self;
+}
+
+impl Number for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn from_usize(n: usize) -> Self {
+        n as f64
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+}
+
+impl Number for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn from_usize(n: usize) -> Self {
+        n as f32
+    }
+
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Number for rust_decimal::Decimal {
+    const ZERO: Self = rust_decimal::Decimal::ZERO;
+    const ONE: Self = rust_decimal::Decimal::ONE;
+
+    fn from_usize(n: usize) -> Self {
+        n.into()
+    }
+
+    fn abs(self) -> Self {
+        rust_decimal::Decimal::abs(&self)
+    }
+}
+
+/// Implement `Next<N>` for `$indicator<N>` with every [`Number`] type `N`
+///
+/// A blanket `impl<T: Number> Next<T>` would overlap with `Next<&Input>` in the eyes of coherence,
+/// since another crate may implement `Number` for a reference.
+/// `storage` marks indicators that are also generic over their [`Storage`](crate::Storage).
+macro_rules! impl_next_for_numbers {
+    (storage $indicator:ident) => {
+        impl_next_for_numbers!(storage $indicator, f64);
+        impl_next_for_numbers!(storage $indicator, f32);
+        #[cfg(feature = "decimal")]
+        impl_next_for_numbers!(storage $indicator, rust_decimal::Decimal);
+    };
+    (storage $indicator:ident, $number:ty) => {
+        impl<S: Storage> Next<$number> for $indicator<$number, S> {
+            fn next(&mut self, input: $number) -> Self::Output {
+                self._next(input)
+            }
+        }
+    };
+    ($indicator:ident) => {
+        impl_next_for_numbers!($indicator, f64);
+        impl_next_for_numbers!($indicator, f32);
+        #[cfg(feature = "decimal")]
+        impl_next_for_numbers!($indicator, rust_decimal::Decimal);
+    };
+    ($indicator:ident, $number:ty) => {
+        impl Next<$number> for $indicator<$number> {
+            fn next(&mut self, input: $number) -> Self::Output {
+                self._next(input)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_helper::*, *};
+
+    #[test]
+    fn f32_follows_f64() -> crate::Result<()> {
+        let mut sma = Sma::new(10)?;
+        let mut sma_f32 = Sma::<f32>::with_period(10)?;
+        let mut ema = Ema::new(10)?;
+        let mut ema_f32 = Ema::<f32>::with_period(10)?;
+
+        for x in RANDOM_DATA.iter().map(|x| x.price()) {
+            assert!((sma.next(x) - sma_f32.next(x as f32) as f64).abs() < 1e-3);
+            assert!((ema.next(x) - ema_f32.next(x as f32) as f64).abs() < 1e-3);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_is_exact() -> crate::Result<()> {
+        use rust_decimal::Decimal;
+
+        struct Trade(Decimal, Decimal);
+        impl Price<Decimal> for Trade {
+            fn price(&self) -> Decimal {
+                self.0
+            }
+        }
+        impl Volume<Decimal> for Trade {
+            fn volume(&self) -> Decimal {
+                self.1
+            }
+        }
+
+        // 0.1 and 0.2 have no exact binary representation
+        let trades = [
+            Trade(Decimal::new(1, 1), Decimal::new(3, 0)),
+            Trade(Decimal::new(2, 1), Decimal::new(1, 0)),
+            Trade(Decimal::new(2, 1), Decimal::new(1, 0)),
+            Trade(Decimal::new(1, 1), Decimal::new(5, 0)),
+        ];
+        let mut sma = Sma::<Decimal>::with_period(2)?;
+        let mut vwap = Vwap::<Decimal>::default();
+        let sma_outputs: Vec<_> = trades.iter().map(|x| sma.next(x)).collect();
+        let vwap_outputs: Vec<_> = trades.iter().map(|x| vwap.next(x)).collect();
+
+        let expected = [(1, 1), (15, 2), (2, 1), (15, 2)];
+        for (output, (num, scale)) in sma_outputs.into_iter().zip(expected) {
+            assert_eq!(output, Decimal::new(num, scale));
+        }
+        // (0.3 + 0.2 + 0.2 + 0.5) / 10
+        assert_eq!(vwap_outputs[3], Decimal::new(12, 2));
+        assert_eq!(vwap_outputs[2], Decimal::new(7, 1) / Decimal::new(5, 0));
+
+        Ok(())
+    }
+}
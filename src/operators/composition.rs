@@ -1,6 +1,7 @@
-use crate::{Current, Indicator, Next, Reset};
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
 
 /// Create a new indicator by combining the two indicators in serial.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Composition<Inner, Outer>
 where
     Inner: Indicator,
@@ -8,6 +9,7 @@ where
 {
     inner: Inner,
     outer: Outer,
+    count: usize,
 }
 impl<Inner, Outer> Composition<Inner, Outer>
 where
@@ -15,7 +17,11 @@ where
     Outer: Indicator + Next<Inner::Output>,
 {
     pub(crate) fn new(inner: Inner, outer: Outer) -> Self {
-        Self { inner, outer }
+        Self {
+            inner,
+            outer,
+            count: 0,
+        }
     }
 
     /// Take out the indicators that composes this indicator
@@ -36,6 +42,7 @@ where
     Outer: Indicator + Next<Inner::Output>,
 {
     fn next(&mut self, input: N) -> Self::Output {
+        self.count = self.count.saturating_add(1);
         self.outer.next(self.inner.next(input))
     }
 }
@@ -56,6 +63,26 @@ where
     fn reset(&mut self) {
         self.inner.reset();
         self.outer.reset();
+        self.count = 0;
+    }
+}
+impl<Inner, Outer> WarmupPeriod for Composition<Inner, Outer>
+where
+    Inner: Indicator + WarmupPeriod,
+    Outer: Indicator + Next<Inner::Output> + WarmupPeriod,
+{
+    fn warmup_period(&self) -> usize {
+        // The outer indicator starts warming up on the first mature output of the inner one
+        self.inner.warmup_period() + self.outer.warmup_period()
+    }
+}
+impl<Inner, Outer> Lookback for Composition<Inner, Outer>
+where
+    Inner: Indicator + Lookback,
+    Outer: Indicator + Next<Inner::Output> + Lookback,
+{
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
     }
 }
 
@@ -104,6 +131,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         },
     }
 }
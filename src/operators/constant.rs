@@ -1,5 +1,6 @@
-use crate::{Current, Indicator, Next, Reset};
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Constant<T: Clone>(T);
 impl<T: Clone> From<T> for Constant<T> {
     fn from(t: T) -> Self {
@@ -22,3 +23,13 @@ impl<T: Clone> Current for Constant<T> {
 impl<T: Clone> Reset for Constant<T> {
     fn reset(&mut self) {}
 }
+impl<T: Clone> WarmupPeriod for Constant<T> {
+    fn warmup_period(&self) -> usize {
+        0
+    }
+}
+impl<T: Clone> Lookback for Constant<T> {
+    fn is_ready(&self) -> bool {
+        true
+    }
+}
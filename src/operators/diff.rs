@@ -1,6 +1,8 @@
-use crate::{Current, Indicator, Next, Reset};
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
 use core::ops::Sub;
 
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Diff<Lhs, Rhs>
 where
     Lhs: Indicator,
@@ -20,6 +22,16 @@ where
         Self { lhs, rhs }
     }
 
+    /// The indicator on the left-hand side of the subtraction
+    pub fn lhs(&self) -> &Lhs {
+        &self.lhs
+    }
+
+    /// The indicator on the right-hand side of the subtraction
+    pub fn rhs(&self) -> &Rhs {
+        &self.rhs
+    }
+
     /// Take out the indicators that composes this indicator
     pub fn decompose(self) -> (Lhs, Rhs) {
         (self.lhs, self.rhs)
@@ -69,6 +81,26 @@ where
         self.rhs.reset();
     }
 }
+impl<Lhs, Rhs> WarmupPeriod for Diff<Lhs, Rhs>
+where
+    Lhs: Indicator + WarmupPeriod,
+    Rhs: Indicator<Output = Lhs::Output> + WarmupPeriod,
+    Lhs::Output: Sub,
+{
+    fn warmup_period(&self) -> usize {
+        self.lhs.warmup_period().max(self.rhs.warmup_period())
+    }
+}
+impl<Lhs, Rhs> Lookback for Diff<Lhs, Rhs>
+where
+    Lhs: Indicator + Lookback,
+    Rhs: Indicator<Output = Lhs::Output> + Lookback,
+    Lhs::Output: Sub,
+{
+    fn is_ready(&self) -> bool {
+        self.lhs.is_ready() && self.rhs.is_ready()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -98,6 +130,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| (x.high(), x.low())),
+            },
         }
     }
 }
@@ -1,11 +1,17 @@
-use crate::{Current, Indicator, Next, Reset};
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Identity<T: Clone>(Option<T>);
 impl<T: Clone> Identity<T> {
     pub fn new() -> Self {
         Identity(None)
     }
 }
+impl<T: Clone> Default for Identity<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 impl<T: Clone> Indicator for Identity<T> {
     type Output = T;
 }
@@ -25,3 +31,13 @@ impl<T: Clone> Reset for Identity<T> {
         self.0 = None;
     }
 }
+impl<T: Clone> WarmupPeriod for Identity<T> {
+    fn warmup_period(&self) -> usize {
+        0
+    }
+}
+impl<T: Clone> Lookback for Identity<T> {
+    fn is_ready(&self) -> bool {
+        self.0.is_some()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Current, Indicator, Next, Reset};
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
 
 /// Create a new indicator that applies a projection to the output of the indicator.
 pub struct Map<I, F, R>
@@ -67,6 +67,39 @@ where
     }
 }
 
+impl<I, F, R> WarmupPeriod for Map<I, F, R>
+where
+    I: Indicator + WarmupPeriod,
+    F: FnMut(I::Output) -> R,
+{
+    fn warmup_period(&self) -> usize {
+        self.i.warmup_period()
+    }
+}
+
+impl<I, F, R> Lookback for Map<I, F, R>
+where
+    I: Indicator + Lookback,
+    F: FnMut(I::Output) -> R,
+{
+    fn is_ready(&self) -> bool {
+        self.i.is_ready()
+    }
+}
+
+/// Only the inner indicator is serialized, as the projection cannot be.
+/// To restore, deserialize the inner indicator and map it again.
+#[cfg(feature = "serde")]
+impl<I, F, R> serde::Serialize for Map<I, F, R>
+where
+    I: Indicator + serde::Serialize,
+    F: FnMut(I::Output) -> R,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.i.serialize(serializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,4 +168,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> crate::Result<()> {
+        let mut sma_map = Map::new(Sma::new(PERIOD)?, f);
+        let (history, inputs) = RANDOM_DATA.split_at(RANDOM_DATA.len() / 2);
+
+        for x in history.iter() {
+            let _ = sma_map.next(x);
+        }
+
+        let json = serde_json::to_string(&sma_map).unwrap();
+        let sma: Sma = serde_json::from_str(&json).unwrap();
+        let mut restored = Map::new(sma, f);
+        for x in inputs.iter() {
+            assert_eq!(restored.next(x), sma_map.next(x));
+        }
+
+        Ok(())
+    }
 }
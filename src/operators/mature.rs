@@ -1,6 +1,7 @@
-use crate::{Current, Indicator, Next, Reset};
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
 
-///
+/// Create a new indicator that outputs `None` until the inner indicator has matured.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mature<I: Indicator> {
     i: I,
     period: usize,
@@ -43,7 +44,7 @@ where
     I: Current,
 {
     fn current(&self) -> Option<Self::Output> {
-        if self.cnt <= 0 {
+        if self.cnt == 0 {
             self.i.current().into()
         } else {
             None
@@ -59,6 +60,22 @@ where
         self.cnt = self.period + 1;
     }
 }
+impl<I: Indicator> WarmupPeriod for Mature<I>
+where
+    I: WarmupPeriod,
+{
+    fn warmup_period(&self) -> usize {
+        self.period.max(self.i.warmup_period())
+    }
+}
+impl<I: Indicator> Lookback for Mature<I>
+where
+    I: Lookback,
+{
+    fn is_ready(&self) -> bool {
+        self.cnt == 0 && self.i.is_ready()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -104,6 +121,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         },
     }
 
@@ -19,5 +19,12 @@ pub use diff::*;
 pub mod together;
 pub use together::*;
 
+#[cfg(feature = "alloc")]
 pub mod window;
+#[cfg(feature = "alloc")]
 pub use window::*;
+
+#[cfg(feature = "alloc")]
+pub mod rolling;
+#[cfg(feature = "alloc")]
+pub use rolling::*;
@@ -0,0 +1,290 @@
+use super::Window;
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
+
+/// Create a new indicator that applies a reducer to the past N output values ​​of the inner indicator.
+///
+/// The window is the same as that of [`Window`], including the padding with the oldest value,
+/// but the reducer borrows it in place, so nothing is allocated after the first input.
+/// [`reducers`] provides the common ones.
+pub struct Rolling<I, F, R>
+where
+    I: Indicator,
+    F: FnMut(&[I::Output]) -> R,
+{
+    window: Window<I>,
+    f: F,
+    _phantom_r: core::marker::PhantomData<R>,
+}
+impl<I, F, R> Rolling<I, F, R>
+where
+    I: Indicator,
+    I::Output: Clone,
+    F: FnMut(&[I::Output]) -> R,
+{
+    pub(crate) fn new(window: Window<I>, f: F) -> Self {
+        Self {
+            window,
+            f,
+            _phantom_r: Default::default(),
+        }
+    }
+
+    /// Take out the window that the reducer is applied to
+    pub fn decompose(self) -> Window<I> {
+        self.window
+    }
+}
+impl<I: Indicator> Window<I>
+where
+    I::Output: Clone,
+{
+    /// Apply `f` to each window in place of copying it out, as [`IndicatorExt::rolling`](crate::IndicatorExt::rolling) does
+    pub fn reduce<F, R>(self, f: F) -> Rolling<I, F, R>
+    where
+        F: FnMut(&[I::Output]) -> R,
+    {
+        Rolling::new(self, f)
+    }
+}
+
+impl<I, F, R> Indicator for Rolling<I, F, R>
+where
+    I: Indicator,
+    F: FnMut(&[I::Output]) -> R,
+{
+    type Output = R;
+}
+
+impl<I, F, R> Current for Rolling<I, F, R>
+where
+    I: Indicator,
+    I::Output: Clone,
+    F: Fn(&[I::Output]) -> R,
+{
+    fn current(&self) -> Option<R> {
+        self.window.current_window().map(|window| (self.f)(window))
+    }
+}
+
+impl<I, F, N, R> Next<N> for Rolling<I, F, R>
+where
+    I: Indicator + Next<N>,
+    I::Output: Clone,
+    F: FnMut(&[I::Output]) -> R,
+{
+    fn next(&mut self, input: N) -> Self::Output {
+        (self.f)(self.window.next_window(input))
+    }
+}
+
+impl<I, F, R> Reset for Rolling<I, F, R>
+where
+    I: Indicator + Reset,
+    F: FnMut(&[I::Output]) -> R,
+{
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+impl<I, F, R> WarmupPeriod for Rolling<I, F, R>
+where
+    I: Indicator + WarmupPeriod,
+    F: FnMut(&[I::Output]) -> R,
+{
+    fn warmup_period(&self) -> usize {
+        self.window.warmup_period()
+    }
+}
+
+impl<I, F, R> Lookback for Rolling<I, F, R>
+where
+    I: Indicator + Lookback,
+    F: FnMut(&[I::Output]) -> R,
+{
+    fn is_ready(&self) -> bool {
+        self.window.is_ready()
+    }
+}
+
+/// Only the window is serialized, as the reducer cannot be.
+/// To restore, deserialize the [`Window`] and [`reduce`](Window::reduce) it again.
+#[cfg(feature = "serde")]
+impl<I, F, R> serde::Serialize for Rolling<I, F, R>
+where
+    I: Indicator + serde::Serialize,
+    I::Output: Clone + serde::Serialize,
+    F: FnMut(&[I::Output]) -> R,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.window.serialize(serializer)
+    }
+}
+
+/// Reducers to pass to [`IndicatorExt::rolling`](crate::IndicatorExt::rolling)
+///
+/// The window runs from the oldest value to the latest.
+/// `mean`, `min`, `max` and `argmax` have nothing to return for the empty window of `rolling(0, ..)`,
+/// and panic on it.
+pub mod reducers {
+    use crate::Number;
+
+    pub fn sum<T: Number>(window: &[T]) -> T {
+        window.iter().fold(T::ZERO, |acc, &x| acc + x)
+    }
+
+    pub fn mean<T: Number>(window: &[T]) -> T {
+        assert!(!window.is_empty(), "mean of an empty window");
+        sum(window) / T::from_usize(window.len())
+    }
+
+    pub fn min<T: Number>(window: &[T]) -> T {
+        window
+            .iter()
+            .copied()
+            .reduce(|acc, x| if x < acc { x } else { acc })
+            .expect("min of an empty window")
+    }
+
+    pub fn max<T: Number>(window: &[T]) -> T {
+        window
+            .iter()
+            .copied()
+            .reduce(|acc, x| if acc < x { x } else { acc })
+            .expect("max of an empty window")
+    }
+
+    /// Position of the largest value, counted from the oldest, which wins ties
+    pub fn argmax<T: Number>(window: &[T]) -> usize {
+        window
+            .iter()
+            .enumerate()
+            .reduce(|acc, x| if acc.1 < x.1 { x } else { acc })
+            .expect("argmax of an empty window")
+            .0
+    }
+
+    pub fn product<T: Number>(window: &[T]) -> T {
+        window.iter().fold(T::ONE, |acc, &x| acc * x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operators::Identity;
+    use crate::test_helper::*;
+    use crate::{IndicatorExt, Max, Min, Price, Sma};
+    use once_cell::sync::Lazy as SyncLazy;
+
+    #[derive(Clone)]
+    struct TestItem(f64);
+    impl Price for TestItem {
+        fn price(&self) -> f64 {
+            self.0
+        }
+    }
+    const PERIOD: usize = 5;
+    const WINDOW_SIZE: usize = 3;
+    static INPUTS: SyncLazy<Box<[TestItem]>> = SyncLazy::new(|| {
+        [100.0, 101.0, 101.0, 102.0, 102.0, 102.0]
+            .into_iter()
+            .map(TestItem)
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    });
+    // Sums of the windows of SMA outputs 100.0, 100.2, 100.4, 100.8, 101.2 and 101.6
+    static OUTPUTS: &[f64] = &[300.0, 300.2, 300.6, 301.4, 302.4, 303.6];
+
+    test_indicator! {
+        new: Sma::new(PERIOD).map(|sma| sma.rolling(WINDOW_SIZE, reducers::sum)),
+        inputs: INPUTS.iter().map(|x| x.price()),
+        outputs: OUTPUTS.iter().copied(),
+        additional_tests: {
+            current: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+            next_ext: {
+                inputs: INPUTS.iter(),
+                outputs: OUTPUTS.iter().copied(),
+            },
+            reset: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
+        },
+    }
+
+    #[test]
+    fn reducers() {
+        let window = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0, 9.0];
+
+        assert_eq!(reducers::sum(&window), 40.0);
+        assert_eq!(reducers::mean(&window[..4]), 2.25);
+        assert_eq!(reducers::min(&window), 1.0);
+        assert_eq!(reducers::max(&window), 9.0);
+        assert_eq!(reducers::argmax(&window), 5);
+        assert_eq!(reducers::product(&window[..5]), 60.0);
+
+        assert_eq!(reducers::sum::<f64>(&[]), 0.0);
+        assert_eq!(reducers::product::<f64>(&[]), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_of_empty_window() {
+        let mut max = Identity::new().rolling(0, reducers::max);
+        let _ = max.next(1.0);
+    }
+
+    #[cfg_attr(miri, ignore)]
+    #[test]
+    fn matches_windowed_indicators() -> crate::Result<()> {
+        let period = 10;
+        let mut sma = Sma::new(period)?;
+        let mut max = Max::new(period)?;
+        let mut min = Min::new(period)?;
+        let mut rolling_mean = Identity::new().rolling(period, reducers::mean);
+        let mut rolling_max = Identity::new().rolling(period, reducers::max);
+        let mut rolling_min = Identity::new().rolling(period, reducers::min);
+
+        for x in RANDOM_DATA.iter().map(|x| x.price()) {
+            assert!((rolling_mean.next(x) - sma.next(x)).abs() < 1e-9);
+            assert_eq!(rolling_max.next(x), max.next(x));
+            assert_eq!(rolling_min.next(x), min.next(x));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn closure() {
+        // Range of the window, which no built-in reducer gives
+        let mut range = Identity::new().rolling(3, |w: &[f64]| reducers::max(w) - reducers::min(w));
+
+        let outputs: Vec<_> = [2.0, 5.0, 3.0, 1.0, 1.0, 1.0]
+            .into_iter()
+            .map(|x| range.next(x))
+            .collect();
+        assert_eq!(outputs, [0.0, 3.0, 3.0, 4.0, 2.0, 0.0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> crate::Result<()> {
+        let mut sma_sum = Sma::new(PERIOD)?.rolling(WINDOW_SIZE, reducers::sum);
+        let (history, inputs) = INPUTS.split_at(INPUTS.len() / 2);
+
+        for x in history.iter() {
+            let _ = sma_sum.next(x);
+        }
+
+        let json = serde_json::to_string(&sma_sum).unwrap();
+        let window: Window<Sma> = serde_json::from_str(&json).unwrap();
+        let mut restored = window.reduce(reducers::sum);
+        for x in inputs.iter() {
+            assert_eq!(restored.next(x), sma_sum.next(x));
+        }
+
+        Ok(())
+    }
+}
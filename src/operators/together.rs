@@ -1,5 +1,6 @@
-use crate::{Current, Indicator, Next, Reset};
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Together<Lhs, Rhs>
 where
     Lhs: Indicator,
@@ -63,6 +64,24 @@ where
         self.rhs.reset();
     }
 }
+impl<Lhs, Rhs> WarmupPeriod for Together<Lhs, Rhs>
+where
+    Lhs: Indicator + WarmupPeriod,
+    Rhs: Indicator + WarmupPeriod,
+{
+    fn warmup_period(&self) -> usize {
+        self.lhs.warmup_period().max(self.rhs.warmup_period())
+    }
+}
+impl<Lhs, Rhs> Lookback for Together<Lhs, Rhs>
+where
+    Lhs: Indicator + Lookback,
+    Rhs: Indicator + Lookback,
+{
+    fn is_ready(&self) -> bool {
+        self.lhs.is_ready() && self.rhs.is_ready()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -115,6 +134,9 @@ mod tests {
             reset: {
                 inputs: RANDOM_DATA.iter().map(|x| x.price()),
             },
+            serde: {
+                inputs: RANDOM_DATA.iter().map(|x| x.price()),
+            },
         }
     }
 }
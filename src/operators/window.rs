@@ -1,33 +1,32 @@
-use std::collections::VecDeque;
+use alloc::{vec, vec::Vec};
 
-use crate::{Current, Indicator, Next, Reset};
+use crate::{Current, Indicator, Lookback, Next, Reset, WarmupPeriod};
 
 /// Create a new indicator that outputs the past N output values ​​of the inner indicator.
-pub struct Window<'a, Inner: Indicator>
-where
-    Self: 'a,
-{
+///
+/// Until N values have come in, the window is padded with the oldest one.
+/// `next` returns a copy of the window, and [`Window::next_window`] lends it out without allocating.
+#[derive(Debug, Clone)]
+pub struct Window<Inner: Indicator> {
     inner: Inner,
     window_size: usize,
-    ring: VecDeque<Inner::Output>,
-    buf: Box<[&'a Inner::Output]>,
-    is_first: bool,
+    /// Every value is written twice, `window_size` apart,
+    /// so that the window is always the contiguous `buf[head..head + window_size]`.
+    buf: Vec<Inner::Output>,
+    head: usize,
+    count: usize,
 }
-impl<'a, Inner: Indicator> Window<'a, Inner>
+impl<Inner: Indicator> Window<Inner>
 where
-    Self: 'a,
+    Inner::Output: Clone,
 {
     pub(crate) fn new(inner: Inner, window_size: usize) -> Self {
-        let buf = unsafe {
-            let a = core::mem::MaybeUninit::<&'a Inner::Output>::zeroed();
-            vec![a.assume_init(); window_size].into_boxed_slice()
-        };
         Self {
             inner,
             window_size,
-            ring: VecDeque::with_capacity(window_size),
-            buf,
-            is_first: true,
+            buf: Vec::with_capacity(2 * window_size),
+            head: 0,
+            count: 0,
         }
     }
 
@@ -36,80 +35,139 @@ where
         self.inner
     }
 
-    fn update_window(&mut self) {
-        let buf = self.buf.as_mut();
-
-        let n = self.window_size - self.ring.len();
-        let mut iter = self.ring.iter();
-
-        unsafe {
-            if n == 0 {
-                for (i, r) in iter.enumerate() {
-                    buf[i] = &*(r as *const _);
-                }
-            } else {
-                let a = iter.next().unwrap();
-                for i in 0..=n {
-                    buf[i] = &*(a as *const _);
-                }
-                for (i, r) in iter.enumerate() {
-                    buf[i + n + 1] = &*(r as *const _);
-                }
-            }
+    /// Same as `next`, but borrows the window instead of copying it out
+    pub fn next_window<N>(&mut self, input: N) -> &[Inner::Output]
+    where
+        Inner: Next<N>,
+    {
+        let value = self.inner.next(input);
+        if self.buf.is_empty() {
+            self.buf = vec![value; 2 * self.window_size];
+        } else {
+            self.buf[self.head] = value.clone();
+            self.buf[self.head + self.window_size] = value;
+            self.head = (self.head + 1) % self.window_size;
         }
+
+        self.count = self.count.saturating_add(1);
+        self.window()
     }
 
-    fn window(&self) -> <Self as Indicator>::Output {
-        unsafe { &*(&self.buf[..] as *const _) }
+    /// Same as `current`, but borrows the window instead of copying it out
+    pub fn current_window(&self) -> Option<&[Inner::Output]> {
+        (0 < self.count).then(|| self.window())
+    }
+
+    fn window(&self) -> &[Inner::Output] {
+        match self.buf.get(self.head..self.head + self.window_size) {
+            Some(window) => window,
+            // No value has come in yet, or the window is empty
+            None => &[],
+        }
     }
 }
-impl<'a, Inner: Indicator> Indicator for Window<'a, Inner>
-where
-    Self: 'a,
-{
-    type Output = &'a [&'a Inner::Output];
+impl<Inner: Indicator> Indicator for Window<Inner> {
+    type Output = Vec<Inner::Output>;
 }
-impl<'a, Inner: Indicator, N> Next<N> for Window<'a, Inner>
+impl<Inner: Indicator, N> Next<N> for Window<Inner>
 where
-    Self: 'a,
     Inner: Next<N>,
+    Inner::Output: Clone,
 {
     fn next(&mut self, input: N) -> Self::Output {
-        let value = self.inner.next(input);
-        if 0 < self.window_size {
-            if self.window_size <= self.ring.len() {
-                let _ = self.ring.pop_front();
-            }
-            self.ring.push_back(value);
-            self.update_window();
-        }
-
-        self.is_first = false;
-        self.window()
+        self.next_window(input).to_vec()
     }
 }
-impl<'a, Inner: Indicator> Current for Window<'a, Inner>
+impl<Inner: Indicator> Current for Window<Inner>
 where
-    Self: 'a,
-    Inner: Current,
+    Inner::Output: Clone,
 {
     fn current(&self) -> Option<Self::Output> {
-        if self.is_first {
-            None
-        } else {
-            self.window().into()
-        }
+        self.current_window().map(<[_]>::to_vec)
     }
 }
-impl<'a, Inner: Indicator> Reset for Window<'a, Inner>
+impl<Inner: Indicator> Reset for Window<Inner>
 where
-    Self: 'a,
     Inner: Reset,
 {
     fn reset(&mut self) {
         self.inner.reset();
-        self.ring.clear();
-        self.is_first = true;
+        self.buf.clear();
+        self.head = 0;
+        self.count = 0;
+    }
+}
+impl<Inner: Indicator> WarmupPeriod for Window<Inner>
+where
+    Inner: WarmupPeriod,
+{
+    fn warmup_period(&self) -> usize {
+        // The oldest value in the window has to be mature as well
+        self.inner.warmup_period() + self.window_size.saturating_sub(1)
+    }
+}
+impl<Inner: Indicator> Lookback for Window<Inner>
+where
+    Inner: Lookback,
+{
+    fn is_ready(&self) -> bool {
+        self.warmup_period() < self.count
+    }
+}
+
+/// State of a [`Window`], which stores the window once rather than the mirrored buffer
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct WindowState<Inner, Values> {
+    inner: Inner,
+    window_size: usize,
+    window: Values,
+    count: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<Inner> serde::Serialize for Window<Inner>
+where
+    Inner: Indicator + serde::Serialize,
+    Inner::Output: Clone + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        WindowState {
+            inner: &self.inner,
+            window_size: self.window_size,
+            window: self.window(),
+            count: self.count,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Inner> serde::Deserialize<'de> for Window<Inner>
+where
+    Inner: Indicator + serde::Deserialize<'de>,
+    Inner::Output: Clone + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = WindowState::<Inner, Vec<Inner::Output>>::deserialize(deserializer)?;
+        let expected_len = if state.count == 0 {
+            0
+        } else {
+            state.window_size
+        };
+        if state.window.len() != expected_len {
+            return Err(serde::de::Error::invalid_length(
+                state.window.len(),
+                &"`window_size` values, or none before the first input",
+            ));
+        }
+
+        let mut window = Self::new(state.inner, state.window_size);
+        if 0 < expected_len {
+            window.buf = [state.window.clone(), state.window].concat();
+        }
+        window.count = state.count;
+        Ok(window)
     }
 }
 
@@ -117,7 +175,8 @@ where
 mod tests {
     use super::*;
     use crate::test_helper::*;
-    use crate::{Price, Sma};
+    use crate::{IndicatorExt, Price, Sma};
+    use alloc::{boxed::Box, string::String};
     use once_cell::sync::Lazy as SyncLazy;
 
     #[derive(Clone)]
@@ -137,13 +196,13 @@ mod tests {
             .into_boxed_slice()
     });
     static OUTPUTS: &[f64] = &[100.0, 100.2, 100.4, 100.8, 101.2, 101.6];
-    static OUTPUTS_WINDOWS: &[&[&f64]] = &[
-        &[&OUTPUTS[0], &OUTPUTS[0], &OUTPUTS[0]],
-        &[&OUTPUTS[0], &OUTPUTS[0], &OUTPUTS[1]],
-        &[&OUTPUTS[0], &OUTPUTS[1], &OUTPUTS[2]],
-        &[&OUTPUTS[1], &OUTPUTS[2], &OUTPUTS[3]],
-        &[&OUTPUTS[2], &OUTPUTS[3], &OUTPUTS[4]],
-        &[&OUTPUTS[3], &OUTPUTS[4], &OUTPUTS[5]],
+    static OUTPUTS_WINDOWS: &[&[f64]] = &[
+        &[OUTPUTS[0], OUTPUTS[0], OUTPUTS[0]],
+        &[OUTPUTS[0], OUTPUTS[0], OUTPUTS[1]],
+        &[OUTPUTS[0], OUTPUTS[1], OUTPUTS[2]],
+        &[OUTPUTS[1], OUTPUTS[2], OUTPUTS[3]],
+        &[OUTPUTS[2], OUTPUTS[3], OUTPUTS[4]],
+        &[OUTPUTS[3], OUTPUTS[4], OUTPUTS[5]],
     ];
 
     #[test]
@@ -161,6 +220,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn next_window() -> crate::Result<()> {
+        let sma = Sma::new(PERIOD)?;
+        let mut window = Window::new(sma, WINDOW_SIZE);
+        assert_eq!(window.current_window(), None);
+
+        for (input, correct) in INPUTS.iter().zip(OUTPUTS_WINDOWS) {
+            assert_eq!(window.next_window(input), *correct);
+            assert_eq!(window.current_window(), Some(*correct));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_window() -> crate::Result<()> {
+        let sma = Sma::new(PERIOD)?;
+        let mut window = Window::new(sma, 0);
+
+        for input in INPUTS.iter() {
+            assert!(window.next(input).is_empty());
+        }
+        assert_eq!(window.current(), Some(vec![]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn owned_outputs() -> crate::Result<()> {
+        // Values that own heap memory, which every overwrite in the buffer has to drop
+        let mut window = Sma::new(PERIOD)?.map(|x| format!("{x:.1}")).window(2);
+
+        let windows: Vec<Vec<String>> = INPUTS.iter().map(|x| window.next(x)).collect();
+        assert_eq!(windows[0], ["100.0", "100.0"]);
+        assert_eq!(windows[1], ["100.0", "100.2"]);
+        assert_eq!(windows[5], ["101.2", "101.6"]);
+
+        window.reset();
+        assert_eq!(window.current(), None);
+        assert_eq!(window.next(&INPUTS[0]), ["100.0", "100.0"]);
+
+        Ok(())
+    }
+
+    // `RANDOM_DATA` is too much for Miri to get through, and the tests above cover the same paths
+    #[cfg_attr(miri, ignore)]
     #[test]
     fn current() -> crate::Result<()> {
         let sma = Sma::new(PERIOD)?;
@@ -174,6 +279,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg_attr(miri, ignore)]
     #[test]
     fn reset() -> crate::Result<()> {
         let sma = Sma::new(PERIOD)?;
@@ -182,18 +288,43 @@ mod tests {
         let mut v: Vec<Vec<f64>> = Vec::with_capacity(RANDOM_DATA.len());
 
         for input in RANDOM_DATA.iter() {
-            let correct = window.next(input);
-            v.push(correct.iter().map(|x| **x).collect());
+            v.push(window.next(input));
         }
 
         window.reset();
 
         for (i, input) in RANDOM_DATA.iter().enumerate() {
-            let value = window.next(input);
-            let correct = v[i].iter().collect::<Vec<&f64>>();
-            assert_eq!(value, &correct[..]);
+            assert_eq!(window.next(input), v[i]);
         }
 
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> crate::Result<()> {
+        let sma = Sma::new(PERIOD)?;
+        let mut window = Window::new(sma, WINDOW_SIZE);
+        let (history, inputs) = INPUTS.split_at(INPUTS.len() / 2);
+
+        let fresh: Window<Sma> = round_trip(&window);
+        assert_eq!(fresh.current(), None);
+
+        for input in history.iter() {
+            let _ = window.next(input);
+        }
+
+        let mut restored: Window<Sma> = round_trip(&window);
+        assert_eq!(restored.current(), window.current());
+        for input in inputs.iter() {
+            assert_eq!(restored.next(input), window.next(input));
+        }
+
+        // A window that does not match `window_size`
+        let mut state = serde_json::to_value(&window).unwrap();
+        state["window"].as_array_mut().unwrap().pop();
+        assert!(serde_json::from_value::<Window<Sma>>(state).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,377 @@
+use crate::{InvalidRangeError, Parameter, Range, Result};
+use core::fmt::Debug;
+
+/// Double-ended queue a windowed indicator keeps its window in
+pub trait Ring<T> {
+    /// Empty ring with room for `capacity` values
+    fn with_capacity(capacity: usize) -> Self;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn front(&self) -> Option<&T>;
+
+    fn back(&self) -> Option<&T>;
+
+    /// Values from front to back
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+
+    fn push_back(&mut self, value: T);
+
+    fn pop_front(&mut self) -> Option<T>;
+
+    fn pop_back(&mut self) -> Option<T>;
+
+    fn clear(&mut self);
+}
+
+/// Where windowed indicators such as [`Sma`](crate::Sma) keep their window
+///
+/// # Example
+///
+/// ```
+/// # use indicator::*;
+/// # fn main() {
+/// // Room for up to 32 values, kept inline
+/// let mut sma = Sma::<f64, Inline<32>>::with_period(2).unwrap();
+///
+/// assert_eq!(sma.next(1.0), 1.0);
+/// assert_eq!(sma.next(2.0), 1.5);
+/// assert!(Sma::<f64, Inline<32>>::with_period(33).is_err());
+/// # }
+/// ```
+pub trait Storage {
+    /// Longest window the storage can hold
+    const CAPACITY: usize;
+
+    type Ring<U: Copy + Default + Debug>: Ring<U> + Clone + Debug;
+}
+
+/// Keep windows in a heap-allocated `VecDeque`, sized by the period
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Heap;
+#[cfg(feature = "alloc")]
+impl Storage for Heap {
+    const CAPACITY: usize = usize::MAX;
+
+    type Ring<U: Copy + Default + Debug> = alloc::collections::VecDeque<U>;
+}
+
+/// Keep windows inline in an [`ArrayRing`] of `N` values, which needs no allocator
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Inline<const N: usize>;
+impl<const N: usize> Storage for Inline<N> {
+    const CAPACITY: usize = N;
+
+    type Ring<U: Copy + Default + Debug> = ArrayRing<U, N>;
+}
+
+/// Storage that indicators use unless another one is named
+#[cfg(feature = "alloc")]
+pub type DefaultStorage = Heap;
+/// Storage that indicators use unless another one is named
+///
+/// Without the `alloc` feature, nothing can hold a window of unknown length,
+/// so the storage has to be named as in `Sma<f64, Inline<32>>`.
+#[cfg(not(feature = "alloc"))]
+pub type DefaultStorage = Inline<0>;
+
+/// Fail unless `S` can hold a window of `len` values, which is `period` plus a few
+pub(crate) fn check_capacity<S: Storage>(period: usize, len: usize) -> Result<()> {
+    if S::CAPACITY < len {
+        Err(InvalidRangeError {
+            param: Parameter::new("period", period),
+            range: Range::UpperBounded {
+                max: S::CAPACITY.saturating_sub(len - period),
+            },
+        }
+        .into())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Ring<T> for alloc::collections::VecDeque<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn front(&self) -> Option<&T> {
+        self.front()
+    }
+
+    fn back(&self) -> Option<&T> {
+        self.back()
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+
+    fn push_back(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+/// Fixed-capacity ring of up to `N` values, stored inline
+///
+/// Pushing onto a full ring panics.
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayRing<T, const N: usize> {
+    buf: [T; N],
+    head: usize,
+    len: usize,
+}
+impl<T: Copy + Default, const N: usize> ArrayRing<T, N> {
+    pub fn new() -> Self {
+        Self {
+            buf: [T::default(); N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Position in `buf` of the `i`-th value from the front
+    fn physical(&self, i: usize) -> usize {
+        let j = self.head + i;
+        if j < N {
+            j
+        } else {
+            j - N
+        }
+    }
+}
+impl<T: Copy + Default, const N: usize> Default for ArrayRing<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Copy + Default, const N: usize> Ring<T> for ArrayRing<T, N> {
+    fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn front(&self) -> Option<&T> {
+        (0 < self.len).then(|| &self.buf[self.head])
+    }
+
+    fn back(&self) -> Option<&T> {
+        (0 < self.len).then(|| &self.buf[self.physical(self.len - 1)])
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        (0..self.len).map(|i| &self.buf[self.physical(i)])
+    }
+
+    fn push_back(&mut self, value: T) {
+        assert!(self.len < N, "ArrayRing of capacity {N} is full");
+        let i = self.physical(self.len);
+        self.buf[i] = value;
+        self.len += 1;
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.buf[self.head];
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.buf[self.physical(self.len)])
+    }
+
+    fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+}
+
+// Serialized as the sequence of values from front to back, since serde has no impls for `[T; N]` of any `N`
+#[cfg(feature = "serde")]
+impl<T: Copy + Default + serde::Serialize, const N: usize> serde::Serialize for ArrayRing<T, N> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.len))?;
+        for value in self.iter() {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de, T: Copy + Default + serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de>
+    for ArrayRing<T, N>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        struct Visitor<T, const N: usize>(core::marker::PhantomData<T>);
+        impl<'de, T: Copy + Default + serde::Deserialize<'de>, const N: usize>
+            serde::de::Visitor<'de> for Visitor<T, N>
+        {
+            type Value = ArrayRing<T, N>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                write!(f, "a sequence of at most {N} values")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> core::result::Result<Self::Value, A::Error> {
+                let mut ring = ArrayRing::new();
+                while let Some(value) = seq.next_element()? {
+                    if ring.len == N {
+                        return Err(serde::de::Error::invalid_length(N + 1, &self));
+                    }
+                    ring.push_back(value);
+                }
+                Ok(ring)
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor(core::marker::PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helper::*, *};
+    use alloc::collections::VecDeque;
+
+    #[test]
+    fn array_ring_matches_vec_deque() {
+        let mut ring = ArrayRing::<usize, 4>::new();
+        let mut deque = VecDeque::new();
+
+        for i in 0..100 {
+            // Fill up, then alternate between both ends
+            match i % 7 {
+                0 | 3 if !deque.is_empty() => assert_eq!(ring.pop_front(), deque.pop_front()),
+                5 if !deque.is_empty() => assert_eq!(ring.pop_back(), deque.pop_back()),
+                _ if deque.len() < 4 => {
+                    ring.push_back(i);
+                    deque.push_back(i);
+                }
+                _ => assert_eq!(ring.pop_front(), deque.pop_front()),
+            }
+            assert_eq!(ring.len(), deque.len());
+            assert_eq!(ring.front(), deque.front());
+            assert_eq!(ring.back(), deque.back());
+            assert!(ring.iter().eq(deque.iter()));
+        }
+
+        ring.clear();
+        assert!(ring.is_empty());
+        assert_eq!(ring.pop_back(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_onto_full_array_ring() {
+        let mut ring = ArrayRing::<f64, 2>::new();
+        for _ in 0..3 {
+            ring.push_back(1.0);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut ring = ArrayRing::<f64, 3>::new();
+        for x in [1.0, 2.0, 3.0, 4.0] {
+            if ring.len() == 3 {
+                let _ = ring.pop_front();
+            }
+            ring.push_back(x);
+        }
+
+        let json = serde_json::to_string(&ring).unwrap();
+        assert_eq!(json, "[2.0,3.0,4.0]");
+        let mut restored: ArrayRing<f64, 3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.pop_front(), Some(2.0));
+        assert_eq!(restored.back(), Some(&4.0));
+
+        assert!(serde_json::from_str::<ArrayRing<f64, 3>>("[1.0,2.0,3.0,4.0]").is_err());
+    }
+
+    /// Check that the indicator built in `Inline` storage outputs exactly what the heap one does.
+    fn assert_inline_matches_heap<Heap, Inline>(mut heap: Heap, mut inline: Inline)
+    where
+        Heap: for<'a> Next<&'a TestItem, Output = f64>,
+        Inline: for<'a> Next<&'a TestItem, Output = f64>,
+    {
+        for x in RANDOM_DATA.iter() {
+            assert_eq!(inline.next(x), heap.next(x));
+        }
+    }
+
+    #[test]
+    fn inline_matches_heap() -> crate::Result<()> {
+        assert_inline_matches_heap(Sma::new(20)?, Sma::<f64, Inline<20>>::with_period(20)?);
+        assert_inline_matches_heap(Wma::new(20)?, Wma::<f64, Inline<32>>::with_period(20)?);
+        assert_inline_matches_heap(Vwma::new(20)?, Vwma::<f64, Inline<20>>::with_period(20)?);
+        assert_inline_matches_heap(
+            Momentum::new(20)?,
+            Momentum::<f64, Inline<21>>::with_period(20)?,
+        );
+        assert_inline_matches_heap(Max::new(20)?, Max::<f64, Inline<20>>::with_period(20)?);
+        assert_inline_matches_heap(Min::new(20)?, Min::<f64, Inline<20>>::with_period(20)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn period_beyond_capacity() {
+        assert!(Sma::<f64, Inline<20>>::with_period(21).is_err());
+        assert!(Momentum::<f64, Inline<20>>::with_period(20).is_err());
+        assert!(Momentum::<f64, Inline<0>>::with_period(1).is_err());
+        assert!(Max::<f64, Inline<4>>::with_period(5).is_err());
+    }
+}
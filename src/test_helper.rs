@@ -94,6 +94,16 @@ impl<T: Round> Round for Option<T> {
     }
 }
 
+/// Serialize to JSON and back
+#[cfg(feature = "serde")]
+pub fn round_trip<T>(value: &T) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
 macro_rules! test_indicator {
     {
         new: $new:expr,
@@ -188,6 +198,14 @@ macro_rules! additional_test {
             news: $news
         }
     };
+    ($new:expr, serde, {
+        inputs: $inputs:expr
+    }) => {
+        test_serde! {
+            new: $new,
+            inputs: $inputs,
+        }
+    };
     ($new:expr, next_ext, {
         inputs: $inputs:expr,
         outputs: $outputs:expr
@@ -255,6 +273,34 @@ macro_rules! test_reset {
     };
 }
 
+macro_rules! test_serde {
+    {
+        new: $new:expr,
+        inputs: $inputs:expr,
+    } => {
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde() -> crate::Result<()> {
+            let new:crate::Result<_> = $new;
+            let mut indicator = new?;
+
+            let inputs: Vec<_> = $inputs.into_iter().collect();
+            let (history, inputs) = inputs.split_at(inputs.len() / 2);
+
+            for x in history.iter().copied() {
+                let _ = Next::next(&mut indicator, x);
+            }
+
+            let mut restored = round_trip(&indicator);
+            for x in inputs.iter().copied() {
+                assert_eq!(Next::next(&mut restored, x), Next::next(&mut indicator, x));
+            }
+
+            Ok(())
+        }
+    };
+}
+
 macro_rules! test_new_invalid_parameter {
     { new: $new:expr} => {
         #[test]
@@ -0,0 +1,329 @@
+use crate::{operators::Mature, Indicator};
+
+/// Number of leading outputs that depend on how the indicator was warmed up
+///
+/// Outputs from the `warmup_period() + 1`-th input on are computed from real inputs only,
+/// so `indicator.mature(indicator.warmup_period())` drops exactly the warm-up.
+pub trait WarmupPeriod {
+    fn warmup_period(&self) -> usize;
+}
+
+/// Readiness of an indicator, propagated through operators
+///
+/// # Example
+///
+/// ```
+/// # use indicator::*;
+/// # fn main() {
+/// let mut macd = Macd::new(2, 3, 2).unwrap().auto_mature();
+/// assert_eq!(macd.lookback(), 3);
+///
+/// for x in [1.0, 2.0, 3.0] {
+///     assert_eq!(macd.next(x), None);
+/// }
+/// assert!(!macd.is_ready());
+/// assert!(macd.next(4.0).is_some());
+/// assert!(macd.is_ready());
+/// # }
+/// ```
+pub trait Lookback: WarmupPeriod {
+    /// Number of inputs to consume before the outputs are ready, which is [`WarmupPeriod::warmup_period`]
+    fn lookback(&self) -> usize {
+        self.warmup_period()
+    }
+
+    /// Whether the latest output was computed after the lookback
+    fn is_ready(&self) -> bool;
+}
+
+pub(crate) mod sealed {
+    /// How recursive averages are started
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Seed {
+        /// Start from the first input
+        First,
+        /// Start from the mean of the first `period` inputs
+        Mean,
+    }
+
+    pub trait Sealed {
+        const SEED: Seed;
+    }
+}
+pub(crate) use sealed::Seed;
+
+/// Warm-up policy, accepted by the `with_warmup` constructor of each indicator
+///
+/// # Example
+///
+/// ```
+/// # use indicator::*;
+/// # fn main() {
+/// let mut ema = Ema::with_warmup(3, NoneUntilFull).unwrap();
+///
+/// assert_eq!(ema.next(1.0), None);
+/// assert_eq!(ema.next(2.0), None);
+/// assert_eq!(ema.next(3.0), Some(2.0));
+/// assert_eq!(ema.next(4.0), Some(3.0));
+/// # }
+/// ```
+pub trait Warmup: sealed::Sealed {
+    /// Indicator built by applying this policy to `I`
+    type Apply<I: Indicator>: Indicator;
+
+    #[doc(hidden)]
+    fn apply<I: Indicator + WarmupPeriod>(indicator: I) -> Self::Apply<I>;
+}
+
+/// Pad windows with the first input and start recursive averages from it
+///
+/// This is what `new` does. Every input produces an output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplicateFirst;
+impl sealed::Sealed for ReplicateFirst {
+    const SEED: Seed = Seed::First;
+}
+impl Warmup for ReplicateFirst {
+    type Apply<I: Indicator> = I;
+
+    fn apply<I: Indicator + WarmupPeriod>(indicator: I) -> Self::Apply<I> {
+        indicator
+    }
+}
+
+/// Start recursive averages from the SMA of their first `period` inputs, as TA-Lib and TradingView do
+///
+/// Until then they output the mean of the inputs so far.
+/// Windowed indicators still pad with the first input, which is gone from the window once warmed up.
+/// Every input produces an output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SmaSeed;
+impl sealed::Sealed for SmaSeed {
+    const SEED: Seed = Seed::Mean;
+}
+impl Warmup for SmaSeed {
+    type Apply<I: Indicator> = I;
+
+    fn apply<I: Indicator + WarmupPeriod>(indicator: I) -> Self::Apply<I> {
+        indicator
+    }
+}
+
+/// Output `None` during the warm-up period, seeding recursive averages as [`SmaSeed`] does
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoneUntilFull;
+impl sealed::Sealed for NoneUntilFull {
+    const SEED: Seed = Seed::Mean;
+}
+impl Warmup for NoneUntilFull {
+    type Apply<I: Indicator> = Mature<I>;
+
+    fn apply<I: Indicator + WarmupPeriod>(indicator: I) -> Self::Apply<I> {
+        let period = indicator.warmup_period();
+        Mature::new(indicator, period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helper::*, *};
+
+    /// Index of the first output after which a fresh indicator agrees with one that has already seen
+    /// a long history, which is where an indicator with finite memory stops depending on its warm-up.
+    fn first_agreeing<I>(indicator: I, value: impl Fn(I::Output) -> f64) -> usize
+    where
+        I: Clone + for<'a> Next<&'a TestItem>,
+    {
+        let (history, inputs) = RANDOM_DATA.split_at(1000);
+        let mut fresh = indicator.clone();
+        let mut warmed = indicator;
+        for x in history.iter() {
+            let _ = warmed.next(x);
+        }
+
+        let mut first = 0;
+        for (i, x) in inputs[..500].iter().enumerate() {
+            let (a, b) = (value(fresh.next(x)), value(warmed.next(x)));
+            if 1e-9 * (1.0 + b.abs()) < (a - b).abs() {
+                first = i + 1;
+            }
+        }
+        first
+    }
+
+    macro_rules! assert_warmup_period {
+        ($($new:expr => $value:expr),* $(,)?) => {
+            $({
+                let indicator = $new.unwrap();
+                let warmup_period = indicator.warmup_period();
+                assert_eq!(first_agreeing(indicator, $value), warmup_period, "{}", stringify!($new));
+            })*
+        };
+        (at most: $($new:expr => $value:expr),* $(,)?) => {
+            $({
+                let indicator = $new.unwrap();
+                let warmup_period = indicator.warmup_period();
+                assert!(first_agreeing(indicator, $value) <= warmup_period, "{}", stringify!($new));
+            })*
+        };
+    }
+
+    #[test]
+    fn warmup_period_of_finite_memory() {
+        assert_warmup_period! {
+            Sma::new(7) => |x| x,
+            Wma::new(7) => |x| x,
+            Vwma::new(7) => |x| x,
+            StandardDeviation::new(7) => |x| x.sd,
+            Momentum::new(7) => |x| x,
+            Roc::new(7) => |x| x,
+            LinearRegression::new(7) => |x| x.slope,
+            Cci::new(7) => |x| x,
+            RollingZScore::new(7) => |x| x.z_score,
+            RollingKurtosis::new(7) => |x| x.kurtosis,
+            BolingerBands::new(7, 2.0) => |x| x.upper_bound,
+            ChaikinMoneyFlow::new(7) => |x| x,
+            MoneyFlowIndex::new(7) => |x| x,
+            Trima::new(7) => |x| x,
+            Hma::new(9) => |x| x,
+        }
+
+        // Order statistics often survive the padding, so only an upper bound holds for these.
+        assert_warmup_period! {
+            at most:
+            Max::new(7) => |x| x,
+            MinIndex::new(7) => |x| x as f64,
+            AroonOscillator::new(7) => |x| x,
+            WilliamsR::new(7) => |x| x,
+            PercentRank::new(7) => |x| x,
+            RollingMedian::new(8) => |x| x,
+            Stochastics::new(7, 3, 4) => |x| x.slow_d,
+            Ichimoku::new(3, 5, 7, 4) => |x| x.cloud.senkou_b,
+        }
+    }
+
+    /// Check that `is_ready` turns on exactly after `lookback() + 1` inputs and off on reset.
+    fn assert_ready_after_lookback<I>(mut indicator: I)
+    where
+        I: Lookback + Reset + for<'a> Next<&'a TestItem>,
+    {
+        let lookback = indicator.lookback();
+        for _ in 0..2 {
+            assert!(!indicator.is_ready());
+            for (i, x) in RANDOM_DATA[..lookback + 3].iter().enumerate() {
+                let _ = indicator.next(x);
+                assert_eq!(indicator.is_ready(), lookback <= i, "{i}");
+            }
+            indicator.reset();
+        }
+    }
+
+    macro_rules! assert_ready_after_lookback {
+        ($($new:expr),* $(,)?) => {
+            $(assert_ready_after_lookback($new.unwrap());)*
+        };
+    }
+
+    #[test]
+    fn ready_after_lookback() {
+        assert_ready_after_lookback! {
+            Sma::new(7),
+            Ema::with_warmup(7, SmaSeed),
+            Rsi::new(7),
+            Macd::new(3, 5, 2),
+            Stochastics::new(7, 3, 4),
+            StochRsi::new(5, 4, 3, 2),
+            ConnorsRsi::new(3, 2, 5),
+            Dmi::new(5, 3),
+            Tsi::new(5, 3),
+            T3::new(3, 0.7),
+            Hma::new(9),
+            Ichimoku::new(3, 5, 7, 4),
+            Atr::new(5),
+            KeltnerChannels::new(5, 3, 2.0),
+            ChaikinOscillator::new(3, 5),
+            AroonOscillator::new(7),
+            ParabolicSar::new(0.02, 0.02, 0.2),
+        }
+        assert_ready_after_lookback(Obv::new());
+    }
+
+    #[test]
+    fn lookback_through_operators() -> crate::Result<()> {
+        let macd = Macd::new(3, 5, 2)?;
+        let histogram = macd.map(|x| x.histogram);
+        let smoothed = histogram.pullback(Sma::new(4)?);
+        // Long EMA, signal and the outer SMA one after another
+        assert_eq!(smoothed.lookback(), 4 + 1 + 3);
+        assert_ready_after_lookback(smoothed);
+
+        let together = Sma::new(3)?.together(Ema::new(6)?);
+        assert_eq!(together.lookback(), 5);
+        assert_ready_after_lookback(together);
+
+        let window = Sma::new(3)?.window(4);
+        assert_eq!(window.lookback(), 2 + 3);
+        assert!(!window.is_ready());
+
+        let mature = Sma::new(3)?.mature(5);
+        assert_eq!(mature.lookback(), 5);
+        assert_ready_after_lookback(mature);
+
+        Ok(())
+    }
+
+    #[test]
+    fn auto_mature() -> crate::Result<()> {
+        let chain = || -> crate::Result<_> {
+            Ok(Macd::new(3, 5, 2)?
+                .map(|x| x.histogram)
+                .pullback(Sma::new(4)?))
+        };
+        let mut plain = chain()?;
+        let mut mature = chain()?.auto_mature();
+        let lookback = plain.lookback();
+
+        for (i, x) in RANDOM_DATA.iter().enumerate() {
+            let value = plain.next(x);
+            if i < lookback {
+                assert_eq!(mature.next(x), None);
+            } else {
+                assert_eq!(mature.next(x), Some(value));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn none_until_full() -> crate::Result<()> {
+        const PERIOD: usize = 5;
+        let mut sma = Sma::new(PERIOD)?;
+        let mut strict = Sma::with_warmup(PERIOD, NoneUntilFull)?;
+
+        for (i, x) in RANDOM_DATA.iter().enumerate() {
+            let value = sma.next(x);
+            if i < PERIOD - 1 {
+                assert_eq!(strict.next(x), None);
+            } else {
+                assert_eq!(strict.next(x), Some(value));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn replicate_first() -> crate::Result<()> {
+        let mut macd = Macd::new(3, 5, 2)?;
+        let mut replicated = Macd::with_warmup(3, 5, 2, ReplicateFirst)?;
+
+        for x in RANDOM_DATA.iter() {
+            assert_eq!(replicated.next(x), macd.next(x));
+        }
+
+        Ok(())
+    }
+}